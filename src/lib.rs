@@ -1,12 +1,69 @@
-use std::fmt::{ self, Formatter, Debug };
-use std::ops::{ Index, IndexMut };
+//! A small and independent crate to perform simple operations with the binary representation of bytes.
+//! 
+//! # Features
+//! 
+//! - `std` (default): implements `std::error::Error` for the error types and enables the `std::io`
+//!   helpers. Implies `alloc`.
+//! - `alloc`: enables the methods returning a `String` or a `Vec`.
+//! - `serde`: serializes bytes as their 8 bit binary string.
+//! - `rand`: generates uniformly distributed random bytes.
+//! - `step_trait`: implements `core::iter::Step` so ranges of bytes can be iterated. The trait is
+//!   still unstable, so this feature **requires a nightly compiler** and makes `--all-features`
+//!   builds fail on stable.
+//! 
+//! # Example
+//! 
+//! ```rust
+//! use binary_byte::ByteBase2;
+//! 
+//! let byte = ByteBase2::from_dec(0b0000_1111);
+//! assert_eq!(byte.ones(), 4);
+//! assert_eq!(format!("{:?}", byte), "00001111".to_string());
+//! ```
 
-/// A binary representation of a byte.
-#[derive(PartialEq)]
-pub struct ByteBase2 {
-    intern: [bool;8]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{ string::String, vec::Vec };
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::str::FromStr;
+use core::iter::{ FromIterator, FusedIterator };
+use core::fmt::{ self, Formatter, Debug, Display, LowerHex, UpperHex, Binary, Octal, Write };
+use core::ops::{ Range, Index, IndexMut, Add, Sub, BitAnd, BitOr, BitXor, Not, BitAndAssign, BitOrAssign, BitXorAssign, Shl, Shr };
+
+/// A binary representation of a word of N bits.
+/// 
+/// Any N supports counting ([ones](#method.ones), [zeros](#method.zeros)), iteration
+/// ([iter](#method.iter), [iter_mut](#method.iter_mut), [map_bits](#method.map_bits),
+/// [for_each_bit](#method.for_each_bit), [fold_bits](#method.fold_bits)), bit access ([get](#method.get),
+/// [get_mut](#method.get_mut) and indexing by `usize`), `Debug`, `Display`, `FromIterator<bool>` and
+/// `From<[bool;N]>`. `from_dec` and `as_dec` are provided for the widths of `u8`, `u16` and `u32`.
+/// The rest of the API (bit setting, shifts and rotations, the bitwise operators, `Ord`, the
+/// arithmetic and the other numeric conversions) is only implemented for
+/// [ByteBase2](type.ByteBase2.html).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::BitsBase2;
+/// 
+/// let word = BitsBase2::<16>::from_dec(0x0F0F);
+/// assert_eq!(word.ones(), 8);
+/// assert_eq!(format!("{:?}", word), "0000111100001111".to_string());
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct BitsBase2<const N: usize> {
+    intern: [bool;N]
 }
 
+/// A binary representation of a byte.
+pub type ByteBase2 = BitsBase2<8>;
+
 /// Error occurred when trying to construct a ByteBase2 with an invalid string pattern.
 /// 
 /// # Example
@@ -14,10 +71,10 @@ pub struct ByteBase2 {
 /// ```rust
 /// use binary_byte::{ ByteBase2, InvalidPattern };
 /// 
-/// assert_eq!(ByteBase2::from_string("foo"), Err(InvalidPattern));
+/// assert_eq!(ByteBase2::from_str_ref("foo"), Err(InvalidPattern));
 /// ```
 /// 
-/// See also [ByteBase2::from_string](struct.ByteBase2.html#method.from_string).
+/// See also [ByteBase2::from_string](struct.BitsBase2.html#method.from_string).
 #[derive(PartialEq)]
 pub struct InvalidPattern;
 
@@ -27,8 +84,176 @@ impl Debug for InvalidPattern {
     }
 }
 
-impl  ByteBase2 {
-    /// Returns how many ones there is in this byte.
+impl Display for InvalidPattern {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidPattern {}
+
+/// Error occurred when trying to construct a ByteBase2 with an invalid hexadecimal string.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, InvalidHex };
+/// 
+/// assert_eq!(ByteBase2::from_hex("fg"), Err(InvalidHex));
+/// ```
+/// 
+/// See also [ByteBase2::from_hex](struct.BitsBase2.html#method.from_hex).
+#[derive(PartialEq)]
+pub struct InvalidHex;
+
+impl Debug for InvalidHex {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The hexadecimal pattern should have 1 or 2 hexadecimal digits, optionally prefixed by 0x.")
+    }
+}
+
+impl Display for InvalidHex {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidHex {}
+
+/// Error occurred when trying to access a ByteBase2 bit with an out of range index.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, OutOfRange };
+/// 
+/// let mut byte = ByteBase2::from_dec(0);
+/// assert_eq!(byte.set_bit(8), Err(OutOfRange));
+/// ```
+/// 
+/// See also [ByteBase2::set_bit](struct.BitsBase2.html#method.set_bit).
+#[derive(PartialEq)]
+pub struct OutOfRange;
+
+impl Debug for OutOfRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The given index or value is out of the accepted range.")
+    }
+}
+
+impl Display for OutOfRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRange {}
+
+/// Error occurred when trying to decode a ByteBase2 whose nibbles aren't valid BCD digits.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, InvalidBcd };
+/// 
+/// assert_eq!(ByteBase2::from_dec(0x4A).from_bcd(), Err(InvalidBcd));
+/// ```
+/// 
+/// See also [ByteBase2::from_bcd](struct.BitsBase2.html#method.from_bcd).
+#[derive(PartialEq)]
+pub struct InvalidBcd;
+
+impl Debug for InvalidBcd {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The byte doesn't represent a valid packed BCD number.")
+    }
+}
+
+impl Display for InvalidBcd {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidBcd {}
+
+/// An iterator over the bits of a ByteBase2.
+/// 
+/// Yields first the least significative bit and last the most significative one. It can also be
+/// walked backwards and always knows how many bits are left.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_str_ref("00000011").unwrap();
+/// let mut byte_iter = byte.iter();
+/// assert_eq!(byte_iter.len(), 8);
+/// assert_eq!(byte_iter.next(), Some(true));
+/// assert_eq!(byte_iter.next_back(), Some(false));
+/// assert_eq!(byte_iter.len(), 6);
+/// ```
+/// 
+/// See also [ByteBase2::iter](struct.BitsBase2.html#method.iter).
+#[derive(Clone, Debug)]
+pub struct Iter<const N: usize = 8> {
+    intern: core::array::IntoIter<bool, N>
+}
+
+impl<const N: usize> Iterator for Iter<N> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.intern.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.intern.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Iter<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.intern.next_back()
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for Iter<N> {}
+
+impl<const N: usize> FusedIterator for Iter<N> {}
+
+/// Names the position of each bit in a ByteBase2, from the least significative `Bit0` to the
+/// most significative `Bit7`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ Bit, ByteBase2 };
+/// 
+/// let mut byte = ByteBase2::from_dec(1);
+/// assert_eq!(byte[Bit::Bit0], true);
+/// byte[Bit::Bit7] = true;
+/// assert_eq!(byte, ByteBase2::from_dec(0b10000001));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Bit {
+    Bit0 = 0,
+    Bit1 = 1,
+    Bit2 = 2,
+    Bit3 = 3,
+    Bit4 = 4,
+    Bit5 = 5,
+    Bit6 = 6,
+    Bit7 = 7
+}
+
+impl<const N: usize> BitsBase2<N> {
+    /// Returns how many ones there is in these bits.
     /// 
     /// # Example
     /// 
@@ -42,138 +267,4300 @@ impl  ByteBase2 {
         self.intern.iter().filter(|bit| **bit).count()
     }
 
-    /// Returns an iterator over this byte's bits.
+    /// Returns how many zeros there is in these bits.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(15);
+    /// assert_eq!(byte.zeros(), 4);
+    /// ```
+    pub fn zeros(&self) -> usize {
+        self.intern.iter().filter(|bit| !**bit).count()
+    }
+
+    /// Returns an iterator over these bits.
     /// 
     /// Yields first the least significative bit and last the most significative one.
+    /// The bits are copied out of this value, so no allocation takes place.
+    /// 
+    /// See also [Iter](struct.Iter.html).
     /// 
     /// # Example
     /// 
     /// ```rust
     /// use binary_byte::ByteBase2;
     /// 
-    /// let byte = ByteBase2::from_string("00000011").unwrap();
+    /// let byte = ByteBase2::from_str_ref("00000011").unwrap();
     /// let mut byte_iter = byte.iter();
     /// assert_eq!(byte_iter.next(), Some(true));
     /// assert_eq!(byte_iter.next(), Some(true));
     /// assert_eq!(byte_iter.next(), Some(false));
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item=bool> {
-        Vec::from(self.intern.as_ref()).into_iter()
+    pub fn iter(&self) -> Iter<N> {
+        Iter { intern: IntoIterator::into_iter(self.intern) }
     }
 
-    /// Tries to create a ByteBase2 from a string representing an 8 bit binary number.
+    /// Returns an iterator over mutable references to these bits.
     /// 
-    /// # Errors
-    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the pattern length is not exactly 8 or
-    /// if any of its characters is different of '1' or '0'.
+    /// Yields first the least significative bit and last the most significative one, like
+    /// [iter](#method.iter).
     /// 
     /// # Example
     /// 
     /// ```rust
-    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// use binary_byte::ByteBase2;
     /// 
-    /// assert!(ByteBase2::from_string("01111001").is_ok());
-    /// assert_eq!(ByteBase2::from_string("12001101"), Err(InvalidPattern));
-    /// assert_eq!(ByteBase2::from_string("101010100"), Err(InvalidPattern));
-    /// assert_eq!(ByteBase2::from_string("1010"), Err(InvalidPattern));
+    /// let mut byte = ByteBase2::from_dec(0b00001111);
+    /// for bit in byte.iter_mut() {
+    ///     *bit = !*bit;
+    /// }
+    /// assert_eq!(byte, ByteBase2::from_dec(0b11110000));
     /// ```
-    pub fn from_string(pattern: impl Into<String>) -> Result<Self, InvalidPattern> {
-        let pattern = pattern.into();
-        if pattern.len() == 8 {
-            let mut intern = [false;8];
-            for (index, bit) in pattern.chars().rev().enumerate() {
-                if bit == '1' { intern[index] = true; }
-                else if bit != '0' { return Err(InvalidPattern); }
-            }
-            return Ok(ByteBase2 { intern });
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=&mut bool> {
+        self.intern.iter_mut()
+    }
+
+    /// Creates a new value by applying the closure to the index and value of each bit.
+    /// 
+    /// Index 0 is the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(0xFF).map_bits(|idx, bit| bit && idx % 2 == 0);
+    /// assert_eq!(byte, ByteBase2::from_dec(0b01010101));
+    /// ```
+    pub fn map_bits(mut self, mut f: impl FnMut(usize, bool) -> bool) -> Self {
+        for (idx, bit) in self.intern.iter_mut().enumerate() {
+            *bit = f(idx, *bit);
         }
-        Err(InvalidPattern)
+        self
     }
 
-    /// Creates a ByteBase2 object from an u8 value.
+    /// Calls the closure with the index and value of each bit, starting from the least significative one.
     /// 
     /// # Example
     /// 
     /// ```rust
     /// use binary_byte::ByteBase2;
     /// 
-    /// let byte = ByteBase2::from_dec(15);
-    /// assert_eq!(format!("{:?}", byte), "00001111".to_string());
+    /// let mut set = Vec::new();
+    /// ByteBase2::from_dec(0b1010).for_each_bit(|idx, bit| if bit { set.push(idx) });
+    /// assert_eq!(set, vec![1, 3]);
     /// ```
-    pub fn from_dec(mut input: u8) -> Self {
-        let mut intern = [false;8];
-        for index in 0..8 {
-            intern[index] = input % 2 == 1;
-            input /= 2;
+    pub fn for_each_bit(&self, mut f: impl FnMut(usize, bool)) {
+        for (idx, bit) in self.intern.iter().enumerate() {
+            f(idx, *bit);
         }
-        ByteBase2 { intern }
     }
 
-    /// Converts a ByteBase2 number to its decimal representation.
+    /// Reduces the bits to a single value, starting from the least significative one.
     /// 
     /// # Example
     /// 
     /// ```rust
     /// use binary_byte::ByteBase2;
     /// 
-    /// let byte = ByteBase2::from_string("00001000").unwrap();
-    /// assert_eq!(byte.as_dec(), 8);
+    /// let byte = ByteBase2::from_dec(0b1011);
+    /// assert_eq!(byte.fold_bits(0, |ones, bit| ones + bit as usize), 3);
+    /// assert_eq!(byte.fold_bits(false, |parity, bit| parity ^ bit), true);
+    /// ```
+    pub fn fold_bits<B>(&self, init: B, f: impl FnMut(B, bool) -> B) -> B {
+        self.iter().fold(init, f)
+    }
+
+    /// Returns the bit at the given index, or `None` if the index is out of range.
+    /// 
+    /// Index 0 access the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(1);
+    /// assert_eq!(byte.get(0), Some(true));
+    /// assert_eq!(byte.get(8), None);
     /// ```
-    pub fn as_dec(&self) -> u8 {
+    pub fn get(&self, idx: usize) -> Option<bool> {
+        self.intern.get(idx).copied()
+    }
+
+    /// Returns a mutable reference to the bit at the given index, or `None` if the index is out of range.
+    /// 
+    /// Index 0 access the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0);
+    /// if let Some(bit) = byte.get_mut(1) { *bit = true; }
+    /// assert_eq!(byte.as_dec(), 2);
+    /// assert_eq!(byte.get_mut(8), None);
+    /// ```
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut bool> {
+        self.intern.get_mut(idx)
+    }
+
+    /// Shared by the `from_dec` constructors of the widths that fit in an u32.
+    const fn from_u32(mut input: u32) -> Self {
+        let mut intern = [false;N];
+        let mut index = 0;
+        while index < N {
+            intern[index] = input % 2 == 1;
+            input /= 2;
+            index += 1;
+        }
+        BitsBase2 { intern }
+    }
+
+    /// Shared by the `as_dec` conversions of the widths that fit in an u32.
+    const fn as_u32(&self) -> u32 {
         let mut output = 0;
-        for index in 0..8 {
+        let mut index = 0;
+        while index < N {
             if self.intern[index] {
-                output += 2_u8.pow(index as u32);
+                output += 2_u32.pow(index as u32);
             }
+            index += 1;
         }
         output
     }
-}
-
-/// Access the bits in this byte.
-/// 
-/// Index 0 access the least significative bit.
-impl Index<usize> for ByteBase2 {
-    type Output = bool;
 
-    fn index(&self, idx: usize) -> &Self::Output {
-        &self.intern[idx]
+    fn binary_digits(&self) -> [u8;N] {
+        let mut digits = [b'0';N];
+        for (digit, bit) in digits.iter_mut().zip(self.intern.iter().rev()) {
+            if *bit { *digit = b'1'; }
+        }
+        digits
     }
 }
 
-/// Mutably access the bits in this byte.
-/// 
-/// Index 0 access the least significative bit.
-impl IndexMut<usize> for ByteBase2 {
-    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        &mut self.intern[idx]
+impl  ByteBase2 {
+    /// Returns how many ones there is in this byte, like [ones](#method.ones) but matching the
+    /// name and return type of `u8::count_ones`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(15);
+    /// assert_eq!(byte.count_ones(), 4);
+    /// ```
+    pub fn count_ones(&self) -> u32 {
+        self.ones() as u32
     }
-}
 
-impl Debug for ByteBase2 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let mut output = String::with_capacity(8);
-        for bit in self.intern.iter().rev() {
-            if *bit { output.push('1'); }
-            else { output.push('0'); }
-        }
-        write!(f, "{}", output)
+    /// Returns how many zeros there is in this byte, like [zeros](#method.zeros) but matching the
+    /// name and return type of `u8::count_zeros`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(7);
+    /// assert_eq!(byte.count_zeros(), 5);
+    /// ```
+    pub fn count_zeros(&self) -> u32 {
+        self.zeros() as u32
     }
-}
 
-#[cfg(test)]
-mod test_mod {
-    use crate::ByteBase2;
+    /// Returns an iterator over this byte's bits in the order they are written.
+    /// 
+    /// Yields first the most significative bit and last the least significative one, matching
+    /// the characters of the `Debug` output from left to right.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000000").unwrap();
+    /// let mut byte_iter = byte.msb_first_iter();
+    /// assert_eq!(byte_iter.next(), Some(true));
+    /// assert_eq!(byte_iter.next(), Some(false));
+    /// ```
+    pub fn msb_first_iter(&self) -> impl Iterator<Item=bool> {
+        self.iter().rev()
+    }
 
-    #[test]
-    #[should_panic]
-    fn index_test() {
-        let byte = ByteBase2::from_dec(15);
-        byte[8];
+    /// Tries to create a ByteBase2 from a string representing an 8 bit binary number.
+    /// 
+    /// Requires the `alloc` feature, see [from_str_ref](#method.from_str_ref) for a borrowing alternative.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the pattern doesn't have exactly 8
+    /// characters or if any of its characters is different of '1' or '0'.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// 
+    /// assert!(ByteBase2::from_string("01111001").is_ok());
+    /// assert_eq!(ByteBase2::from_string("12001101"), Err(InvalidPattern));
+    /// assert_eq!(ByteBase2::from_string("101010100"), Err(InvalidPattern));
+    /// assert_eq!(ByteBase2::from_string("1010"), Err(InvalidPattern));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn from_string(pattern: impl Into<String>) -> Result<Self, InvalidPattern> {
+        ByteBase2::from_str_ref(&pattern.into())
     }
 
-    #[test]
-    fn debug_test() {
-        assert_eq!(format!("{:?}", ByteBase2::from_dec(15)), "00001111".to_string());
+    /// Tries to create a ByteBase2 from a borrowed string representing an 8 bit binary number.
+    /// 
+    /// Behaves exactly like [from_string](#method.from_string), but reads the pattern in place
+    /// instead of converting it into an owned `String` first.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the pattern doesn't have exactly 8
+    /// characters or if any of its characters is different of '1' or '0'.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// 
+    /// assert_eq!(ByteBase2::from_str_ref("00001111"), Ok(ByteBase2::from_dec(15)));
+    /// assert_eq!(ByteBase2::from_str_ref("0000111"), Err(InvalidPattern));
+    /// ```
+    pub fn from_str_ref(pattern: &str) -> Result<Self, InvalidPattern> {
+        if pattern.chars().count() == 8 {
+            let mut intern = [false;8];
+            for (index, bit) in pattern.chars().rev().enumerate() {
+                if bit == '1' { intern[index] = true; }
+                else if bit != '0' { return Err(InvalidPattern); }
+            }
+            return Ok(ByteBase2 { intern });
+        }
+        Err(InvalidPattern)
+    }
+
+    /// Tries to create a ByteBase2 from a string representing an 8 bit binary number, ignoring
+    /// any `_` or space separators between its digits.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the pattern doesn't have exactly 8
+    /// '0' or '1' characters, or if any of its characters is not a digit or a separator.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// 
+    /// assert_eq!(ByteBase2::from_string_grouped("0000_1111"), Ok(ByteBase2::from_dec(15)));
+    /// assert_eq!(ByteBase2::from_string_grouped("00 00 11 11"), Ok(ByteBase2::from_dec(15)));
+    /// assert_eq!(ByteBase2::from_string_grouped("0000__11"), Err(InvalidPattern));
+    /// ```
+    pub fn from_string_grouped(pattern: &str) -> Result<Self, InvalidPattern> {
+        let mut digits = pattern.chars().rev().filter(|digit| *digit != '_' && *digit != ' ');
+        let mut intern = [false;8];
+        for bit in intern.iter_mut() {
+            match digits.next() {
+                Some('1') => *bit = true,
+                Some('0') => {},
+                _ => return Err(InvalidPattern)
+            }
+        }
+        if digits.next().is_some() { return Err(InvalidPattern); }
+        Ok(ByteBase2 { intern })
+    }
+
+    /// Tries to create a ByteBase2 from a binary literal, optionally prefixed by `0b` or `0B`.
+    /// 
+    /// The digits after the prefix are parsed like in [from_string_grouped](#method.from_string_grouped),
+    /// so `_` and space separators are also accepted.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the pattern doesn't have exactly 8
+    /// '0' or '1' characters after the prefix, or if any of its characters is not a digit or a separator.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// 
+    /// assert_eq!(ByteBase2::from_binary_str("0b11110000"), Ok(ByteBase2::from_dec(240)));
+    /// assert_eq!(ByteBase2::from_binary_str("0b0000_1111"), Ok(ByteBase2::from_dec(15)));
+    /// assert_eq!(ByteBase2::from_binary_str("11110000"), Ok(ByteBase2::from_dec(240)));
+    /// assert_eq!(ByteBase2::from_binary_str("0b"), Err(InvalidPattern));
+    /// ```
+    pub fn from_binary_str(pattern: &str) -> Result<Self, InvalidPattern> {
+        let digits = pattern.strip_prefix("0b")
+            .or_else(|| pattern.strip_prefix("0B"))
+            .unwrap_or(pattern);
+        ByteBase2::from_string_grouped(digits)
+    }
+
+    /// Creates a ByteBase2 object from an u8 value.
+    /// 
+    /// Can be used in constant contexts.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// const MASK: ByteBase2 = ByteBase2::from_dec(0b1010_1010);
+    /// let byte = ByteBase2::from_dec(15);
+    /// assert_eq!(format!("{:?}", byte), "00001111".to_string());
+    /// assert_eq!(format!("{:?}", MASK), "10101010".to_string());
+    /// ```
+    pub const fn from_dec(input: u8) -> Self {
+        Self::from_u32(input as u32)
+    }
+
+    /// Converts a ByteBase2 number to its decimal representation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00001000").unwrap();
+    /// assert_eq!(byte.as_dec(), 8);
+    /// ```
+    pub const fn as_dec(&self) -> u8 {
+        self.as_u32() as u8
+    }
+
+    /// Creates a ByteBase2 object from the two's complement representation of an i8 value.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_i8(-1);
+    /// assert_eq!(format!("{:?}", byte), "11111111".to_string());
+    /// ```
+    pub const fn from_i8(input: i8) -> Self {
+        ByteBase2::from_dec(input as u8)
+    }
+
+    /// Converts a ByteBase2 number to its two's complement signed representation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000000").unwrap();
+    /// assert_eq!(byte.as_i8(), -128);
+    /// ```
+    pub const fn as_i8(&self) -> i8 {
+        self.as_dec() as i8
+    }
+
+    /// Shifts the bits of this byte towards the least significative end, preserving the sign bit.
+    /// 
+    /// The byte is treated as a two's complement number: the vacated high bits are filled with copies
+    /// of bit 7, so shifting by 8 or more yields all zeros for positive bytes and all ones for negative ones.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000000").unwrap();
+    /// assert_eq!(byte.arithmetic_shift_right(2), ByteBase2::from_str_ref("11100000").unwrap());
+    /// ```
+    pub fn arithmetic_shift_right(self, n: u32) -> ByteBase2 {
+        let mut output = self >> n;
+        if self.intern[7] {
+            for bit in output.intern.iter_mut().rev().take(n as usize) {
+                *bit = true;
+            }
+        }
+        output
+    }
+
+    /// Cyclically shifts the bits of this byte towards the most significative end.
+    /// 
+    /// The bits shifted past index 7 wrap around to index 0. The rotation count is taken modulo 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000001").unwrap();
+    /// assert_eq!(byte.rotate_left(1), ByteBase2::from_str_ref("00000011").unwrap());
+    /// assert_eq!(byte.rotate_left(u32::MAX), byte.rotate_left(7));
+    /// ```
+    pub fn rotate_left(mut self, n: u32) -> ByteBase2 {
+        self.intern.rotate_right((n % 8) as usize);
+        self
+    }
+
+    /// Cyclically shifts the bits of this byte towards the least significative end.
+    /// 
+    /// The bits shifted past index 0 wrap around to index 7. The rotation count is taken modulo 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000001").unwrap();
+    /// assert_eq!(byte.rotate_right(1), ByteBase2::from_str_ref("11000000").unwrap());
+    /// assert_eq!(byte.rotate_right(u32::MAX), byte.rotate_right(7));
+    /// ```
+    pub fn rotate_right(mut self, n: u32) -> ByteBase2 {
+        self.intern.rotate_left((n % 8) as usize);
+        self
+    }
+
+    /// Returns a byte with the bit order of this one reversed.
+    /// 
+    /// The least significative bit becomes the most significative one and vice versa.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00000001").unwrap();
+    /// assert_eq!(byte.reverse(), ByteBase2::from_str_ref("10000000").unwrap());
+    /// ```
+    pub fn reverse(mut self) -> ByteBase2 {
+        self.reverse_in_place();
+        self
+    }
+
+    /// Reverses the bit order of this byte in place.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_str_ref("00000011").unwrap();
+    /// byte.reverse_in_place();
+    /// assert_eq!(byte, ByteBase2::from_str_ref("11000000").unwrap());
+    /// ```
+    pub fn reverse_in_place(&mut self) {
+        self.intern.reverse();
+    }
+
+    /// Returns how many consecutive zeros there is starting from the most significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00010000").unwrap();
+    /// assert_eq!(byte.leading_zeros(), 3);
+    /// ```
+    pub fn leading_zeros(&self) -> u32 {
+        self.intern.iter().rev().take_while(|bit| !**bit).count() as u32
+    }
+
+    /// Returns how many consecutive zeros there is starting from the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00010000").unwrap();
+    /// assert_eq!(byte.trailing_zeros(), 4);
+    /// ```
+    pub fn trailing_zeros(&self) -> u32 {
+        self.intern.iter().take_while(|bit| !**bit).count() as u32
+    }
+
+    /// Returns how many consecutive ones there is starting from the most significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("11100000").unwrap();
+    /// assert_eq!(byte.leading_ones(), 3);
+    /// ```
+    pub fn leading_ones(&self) -> u32 {
+        self.intern.iter().rev().take_while(|bit| **bit).count() as u32
+    }
+
+    /// Returns how many consecutive ones there is starting from the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00000111").unwrap();
+    /// assert_eq!(byte.trailing_ones(), 3);
+    /// ```
+    pub fn trailing_ones(&self) -> u32 {
+        self.intern.iter().take_while(|bit| **bit).count() as u32
+    }
+
+    /// Sets the bit at the given index to one.
+    /// 
+    /// Index 0 access the least significative bit.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the index is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0);
+    /// assert_eq!(byte.set_bit(3), Ok(()));
+    /// assert_eq!(byte.as_dec(), 8);
+    /// assert_eq!(byte.set_bit(8), Err(OutOfRange));
+    /// ```
+    pub fn set_bit(&mut self, idx: usize) -> Result<(), OutOfRange> {
+        *self.get_mut(idx).ok_or(OutOfRange)? = true;
+        Ok(())
+    }
+
+    /// Clears the bit at the given index to zero.
+    /// 
+    /// Index 0 access the least significative bit.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the index is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(15);
+    /// assert_eq!(byte.clear_bit(0), Ok(()));
+    /// assert_eq!(byte.as_dec(), 14);
+    /// assert_eq!(byte.clear_bit(8), Err(OutOfRange));
+    /// ```
+    pub fn clear_bit(&mut self, idx: usize) -> Result<(), OutOfRange> {
+        *self.get_mut(idx).ok_or(OutOfRange)? = false;
+        Ok(())
+    }
+
+    /// Flips the bit at the given index.
+    /// 
+    /// Index 0 access the least significative bit.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the index is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(1);
+    /// assert_eq!(byte.toggle_bit(0), Ok(()));
+    /// assert_eq!(byte.toggle_bit(1), Ok(()));
+    /// assert_eq!(byte.as_dec(), 2);
+    /// assert_eq!(byte.toggle_bit(8), Err(OutOfRange));
+    /// ```
+    pub fn toggle_bit(&mut self, idx: usize) -> Result<(), OutOfRange> {
+        let bit = self.get_mut(idx).ok_or(OutOfRange)?;
+        *bit = !*bit;
+        Ok(())
+    }
+
+    /// Tries to create a ByteBase2 from a string representing a 2 digit hexadecimal number.
+    /// 
+    /// The pattern may have one or two case insensitive hexadecimal digits, optionally prefixed by `0x`.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidHex](struct.InvalidHex.html)) if the pattern has no digits, more
+    /// than 2 digits or if any of its characters is not an hexadecimal digit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidHex };
+    /// 
+    /// assert_eq!(ByteBase2::from_hex("F"), Ok(ByteBase2::from_dec(15)));
+    /// assert_eq!(ByteBase2::from_hex("0xaB"), Ok(ByteBase2::from_dec(0xAB)));
+    /// assert_eq!(ByteBase2::from_hex("100"), Err(InvalidHex));
+    /// assert_eq!(ByteBase2::from_hex("fg"), Err(InvalidHex));
+    /// ```
+    pub fn from_hex(pattern: &str) -> Result<Self, InvalidHex> {
+        let digits = pattern.strip_prefix("0x")
+            .or_else(|| pattern.strip_prefix("0X"))
+            .unwrap_or(pattern);
+        if digits.is_empty() || digits.len() > 2 { return Err(InvalidHex); }
+        let mut output = 0;
+        for digit in digits.chars() {
+            output = output * 16 + digit.to_digit(16).ok_or(InvalidHex)? as u8;
+        }
+        Ok(ByteBase2::from_dec(output))
+    }
+
+    /// Converts a ByteBase2 number to its 2 digit lowercase hexadecimal representation.
+    /// 
+    /// Requires the `alloc` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(15);
+    /// assert_eq!(byte.as_hex(), "0f".to_string());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn as_hex(&self) -> String {
+        alloc::format!("{:02x}", self.as_dec())
+    }
+
+    /// Returns the bits of this byte as an array.
+    /// 
+    /// Index 0 of the array holds the least significative bit, matching the byte's own indexing.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let bits = ByteBase2::from_dec(1).to_array();
+    /// assert_eq!(bits, [true, false, false, false, false, false, false, false]);
+    /// ```
+    pub fn to_array(&self) -> [bool;8] {
+        self.intern
+    }
+
+    /// Returns the two's complement negation of this byte, wrapping around on overflow.
+    /// 
+    /// Unlike the bitwise complement (`!`), this flips every bit and then adds one, so the result
+    /// holds the negated signed value. The only exception is `10000000` (-128), which negates to itself.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(1).negate(), ByteBase2::from_dec(255));
+    /// assert_eq!(ByteBase2::from_dec(0).negate(), ByteBase2::from_dec(0));
+    /// ```
+    pub fn negate(self) -> ByteBase2 {
+        ByteBase2::from_dec(self.as_dec().wrapping_neg())
+    }
+
+    /// Adds two bytes, wrapping around at the boundary of the type.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(200).wrapping_add(ByteBase2::from_dec(55)), ByteBase2::from_dec(255));
+    /// assert_eq!(ByteBase2::from_dec(255).wrapping_add(ByteBase2::from_dec(1)), ByteBase2::from_dec(0));
+    /// ```
+    pub fn wrapping_add(self, rhs: ByteBase2) -> ByteBase2 {
+        ByteBase2::from_dec(self.as_dec().wrapping_add(rhs.as_dec()))
+    }
+
+    /// Adds two bytes, wrapping around at the boundary of the type.
+    /// 
+    /// Returns the wrapped sum along with a boolean telling whether there was a carry out of bit 7.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(1).overflowing_add(ByteBase2::from_dec(2)), (ByteBase2::from_dec(3), false));
+    /// assert_eq!(ByteBase2::from_dec(255).overflowing_add(ByteBase2::from_dec(1)), (ByteBase2::from_dec(0), true));
+    /// ```
+    pub fn overflowing_add(self, rhs: ByteBase2) -> (ByteBase2, bool) {
+        let (output, carry) = self.as_dec().overflowing_add(rhs.as_dec());
+        (ByteBase2::from_dec(output), carry)
+    }
+
+    /// Adds two bytes and an incoming carry bit, like a chain of 8 full adders.
+    /// 
+    /// Returns the low 8 bits of the sum along with the outgoing carry bit, which allows chaining
+    /// the addition of multiple bytes starting from the least significative one.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(255).full_adder(ByteBase2::from_dec(0), true), (ByteBase2::from_dec(0), true));
+    /// assert_eq!(ByteBase2::from_dec(1).full_adder(ByteBase2::from_dec(2), true), (ByteBase2::from_dec(4), false));
+    /// ```
+    pub fn full_adder(self, rhs: ByteBase2, carry_in: bool) -> (ByteBase2, bool) {
+        let sum = self.as_dec() as u16 + rhs.as_dec() as u16 + carry_in as u16;
+        (ByteBase2::from_dec(sum as u8), sum > 0xFF)
+    }
+
+    /// Adds two bytes, returning `None` if the sum doesn't fit in a byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(100).checked_add(ByteBase2::from_dec(100)), Some(ByteBase2::from_dec(200)));
+    /// assert_eq!(ByteBase2::from_dec(200).checked_add(ByteBase2::from_dec(100)), None);
+    /// ```
+    pub fn checked_add(self, rhs: ByteBase2) -> Option<ByteBase2> {
+        self.as_dec().checked_add(rhs.as_dec()).map(ByteBase2::from_dec)
+    }
+
+    /// Adds two bytes, clamping the sum to 255 instead of overflowing.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(100).saturating_add(ByteBase2::from_dec(100)), ByteBase2::from_dec(200));
+    /// assert_eq!(ByteBase2::from_dec(200).saturating_add(ByteBase2::from_dec(100)), ByteBase2::from_dec(255));
+    /// ```
+    pub fn saturating_add(self, rhs: ByteBase2) -> ByteBase2 {
+        ByteBase2::from_dec(self.as_dec().saturating_add(rhs.as_dec()))
+    }
+
+    /// Subtracts two bytes, wrapping around at the boundary of the type.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(5).wrapping_sub(ByteBase2::from_dec(3)), ByteBase2::from_dec(2));
+    /// assert_eq!(ByteBase2::from_dec(0).wrapping_sub(ByteBase2::from_dec(1)), ByteBase2::from_dec(255));
+    /// ```
+    pub fn wrapping_sub(self, rhs: ByteBase2) -> ByteBase2 {
+        ByteBase2::from_dec(self.as_dec().wrapping_sub(rhs.as_dec()))
+    }
+
+    /// Subtracts two bytes, wrapping around at the boundary of the type.
+    /// 
+    /// Returns the wrapped difference along with a boolean telling whether a borrow was needed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(5).overflowing_sub(ByteBase2::from_dec(3)), (ByteBase2::from_dec(2), false));
+    /// assert_eq!(ByteBase2::from_dec(0).overflowing_sub(ByteBase2::from_dec(1)), (ByteBase2::from_dec(255), true));
+    /// ```
+    pub fn overflowing_sub(self, rhs: ByteBase2) -> (ByteBase2, bool) {
+        let (output, borrow) = self.as_dec().overflowing_sub(rhs.as_dec());
+        (ByteBase2::from_dec(output), borrow)
+    }
+
+    /// Multiplies two bytes, returning the exact product as a 16 bit value.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(16).full_mul(ByteBase2::from_dec(16)), 256);
+    /// assert_eq!(ByteBase2::from_dec(255).full_mul(ByteBase2::from_dec(255)), 65025);
+    /// ```
+    pub fn full_mul(self, rhs: ByteBase2) -> u16 {
+        self.as_dec() as u16 * rhs.as_dec() as u16
+    }
+
+    /// Multiplies two bytes, wrapping around at the boundary of the type.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(200).wrapping_mul(ByteBase2::from_dec(2)), ByteBase2::from_dec(144));
+    /// ```
+    pub fn wrapping_mul(self, rhs: ByteBase2) -> ByteBase2 {
+        ByteBase2::from_dec(self.as_dec().wrapping_mul(rhs.as_dec()))
+    }
+
+    /// Divides two bytes, returning None if the divisor is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(10).checked_div(ByteBase2::from_dec(3)), Some(ByteBase2::from_dec(3)));
+    /// assert_eq!(ByteBase2::from_dec(10).checked_div(ByteBase2::from_dec(0)), None);
+    /// ```
+    pub fn checked_div(self, rhs: ByteBase2) -> Option<ByteBase2> {
+        self.as_dec().checked_div(rhs.as_dec()).map(ByteBase2::from_dec)
+    }
+
+    /// Calculates the remainder of the division of two bytes, returning None if the divisor is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(10).checked_rem(ByteBase2::from_dec(3)), Some(ByteBase2::from_dec(1)));
+    /// assert_eq!(ByteBase2::from_dec(10).checked_rem(ByteBase2::from_dec(0)), None);
+    /// ```
+    pub fn checked_rem(self, rhs: ByteBase2) -> Option<ByteBase2> {
+        self.as_dec().checked_rem(rhs.as_dec()).map(ByteBase2::from_dec)
+    }
+
+    /// Returns the value of the four most significative bits of this byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0xAB).high_nibble(), 0xA);
+    /// ```
+    pub fn high_nibble(&self) -> u8 {
+        self.as_dec() >> 4
+    }
+
+    /// Returns the value of the four least significative bits of this byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0xAB).low_nibble(), 0xB);
+    /// ```
+    pub fn low_nibble(&self) -> u8 {
+        self.as_dec() & 0x0F
+    }
+
+    /// Replaces the four most significative bits of this byte with the given value.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the value is greater than 15.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0x0B);
+    /// assert_eq!(byte.set_high_nibble(0xA), Ok(()));
+    /// assert_eq!(byte, ByteBase2::from_dec(0xAB));
+    /// assert_eq!(byte.set_high_nibble(16), Err(OutOfRange));
+    /// ```
+    pub fn set_high_nibble(&mut self, value: u8) -> Result<(), OutOfRange> {
+        if value > 15 { return Err(OutOfRange); }
+        *self = ByteBase2::from_dec(value << 4 | self.low_nibble());
+        Ok(())
+    }
+
+    /// Replaces the four least significative bits of this byte with the given value.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the value is greater than 15.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0xA0);
+    /// assert_eq!(byte.set_low_nibble(0xB), Ok(()));
+    /// assert_eq!(byte, ByteBase2::from_dec(0xAB));
+    /// assert_eq!(byte.set_low_nibble(16), Err(OutOfRange));
+    /// ```
+    pub fn set_low_nibble(&mut self, value: u8) -> Result<(), OutOfRange> {
+        if value > 15 { return Err(OutOfRange); }
+        *self = ByteBase2::from_dec(self.high_nibble() << 4 | value);
+        Ok(())
+    }
+
+    /// Creates a ByteBase2 from the values of its four most significative bits and its four least
+    /// significative bits.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if any of the values is greater than 15.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// assert_eq!(ByteBase2::combine_nibbles(0xA, 0xB), Ok(ByteBase2::from_dec(0xAB)));
+    /// assert_eq!(ByteBase2::combine_nibbles(0x10, 0xB), Err(OutOfRange));
+    /// ```
+    pub fn combine_nibbles(high: u8, low: u8) -> Result<Self, OutOfRange> {
+        if high > 15 || low > 15 { return Err(OutOfRange); }
+        Ok(ByteBase2::from_dec(high << 4 | low))
+    }
+
+    /// Encodes a decimal value as packed BCD, the tens digit in the high nibble and the units digit
+    /// in the low nibble.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if the value is greater than 99.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// assert_eq!(ByteBase2::to_bcd(42), Ok(ByteBase2::from_dec(0b0100_0010)));
+    /// assert_eq!(ByteBase2::to_bcd(100), Err(OutOfRange));
+    /// ```
+    pub fn to_bcd(value: u8) -> Result<ByteBase2, OutOfRange> {
+        if value > 99 { return Err(OutOfRange); }
+        ByteBase2::combine_nibbles(value / 10, value % 10)
+    }
+
+    /// Decodes this byte as packed BCD, the tens digit in the high nibble and the units digit in
+    /// the low nibble.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidBcd](struct.InvalidBcd.html)) if any of the nibbles is greater than 9.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidBcd };
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b0100_0010).from_bcd(), Ok(42));
+    /// assert_eq!(ByteBase2::from_dec(0x4A).from_bcd(), Err(InvalidBcd));
+    /// ```
+    pub fn from_bcd(&self) -> Result<u8, InvalidBcd> {
+        let (tens, units) = (self.high_nibble(), self.low_nibble());
+        if tens > 9 || units > 9 { return Err(InvalidBcd); }
+        Ok(tens * 10 + units)
+    }
+
+    /// Returns a byte with the four high bits and the four low bits of this one exchanged.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0xAB).swap_nibbles(), ByteBase2::from_dec(0xBA));
+    /// ```
+    pub fn swap_nibbles(self) -> ByteBase2 {
+        self.rotate_left(4)
+    }
+
+    /// Returns how many bit positions differ between this byte and another one.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(0b1010);
+    /// assert_eq!(byte.hamming_distance(&ByteBase2::from_dec(0b0101)), 4);
+    /// assert_eq!(byte.hamming_distance(&byte), 0);
+    /// ```
+    pub fn hamming_distance(&self, other: &ByteBase2) -> u32 {
+        (self ^ other).ones() as u32
+    }
+
+    /// Returns the parity of this byte: `true` if it has an odd number of ones, `false` otherwise.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_dec(0b111).parity());
+    /// assert!(!ByteBase2::from_dec(0b11).parity());
+    /// ```
+    pub fn parity(&self) -> bool {
+        self.ones() % 2 == 1
+    }
+
+    /// Returns the bit that, appended to this byte, makes its total number of ones even.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_dec(0b111).even_parity_bit());
+    /// assert!(!ByteBase2::from_dec(0b11).even_parity_bit());
+    /// ```
+    pub fn even_parity_bit(&self) -> bool {
+        self.parity()
+    }
+
+    /// Returns `true` if exactly one bit of this byte is set.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_dec(8).is_power_of_two());
+    /// assert!(!ByteBase2::from_dec(6).is_power_of_two());
+    /// ```
+    pub fn is_power_of_two(&self) -> bool {
+        self.ones() == 1
+    }
+
+    /// Returns `true` if every bit of this byte is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_dec(0).is_zero());
+    /// assert!(!ByteBase2::from_dec(1).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.intern.iter().all(|bit| !*bit)
+    }
+
+    /// Returns `true` if every bit of this byte is one.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_dec(255).is_all_ones());
+    /// assert!(!ByteBase2::from_dec(254).is_all_ones());
+    /// ```
+    pub fn is_all_ones(&self) -> bool {
+        self.intern.iter().all(|bit| *bit)
+    }
+
+    /// Converts this byte from plain binary to its reflected Gray code.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b0010).to_gray(), ByteBase2::from_dec(0b0011));
+    /// ```
+    pub fn to_gray(self) -> ByteBase2 {
+        self ^ (self >> 1_usize)
+    }
+
+    /// Converts this byte from its reflected Gray code back to plain binary.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b0011).from_gray(), ByteBase2::from_dec(0b0010));
+    /// ```
+    pub fn from_gray(mut self) -> ByteBase2 {
+        for index in (0..7).rev() {
+            self.intern[index] ^= self.intern[index + 1];
+        }
+        self
+    }
+
+    /// Shifts the bits of this byte towards the most significative end, returning `None` if the
+    /// shift amount is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(1).checked_shl(7), Some(ByteBase2::from_dec(128)));
+    /// assert_eq!(ByteBase2::from_dec(1).checked_shl(8), None);
+    /// ```
+    pub fn checked_shl(self, n: u32) -> Option<ByteBase2> {
+        if n < 8 { Some(self << n) } else { None }
+    }
+
+    /// Shifts the bits of this byte towards the least significative end, returning `None` if the
+    /// shift amount is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(128).checked_shr(7), Some(ByteBase2::from_dec(1)));
+    /// assert_eq!(ByteBase2::from_dec(128).checked_shr(8), None);
+    /// ```
+    pub fn checked_shr(self, n: u32) -> Option<ByteBase2> {
+        if n < 8 { Some(self >> n) } else { None }
+    }
+
+    /// Combines two bytes into an u16 value.
+    /// 
+    /// `high` becomes the 8 most significative bits of the result and `low` the 8 least significative ones.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let word = ByteBase2::to_u16(&ByteBase2::from_dec(0x12), &ByteBase2::from_dec(0x34));
+    /// assert_eq!(word, 0x1234);
+    /// ```
+    pub fn to_u16(high: &ByteBase2, low: &ByteBase2) -> u16 {
+        (high.as_dec() as u16) << 8 | low.as_dec() as u16
+    }
+
+    /// Splits an u16 value into two bytes.
+    /// 
+    /// Returns `[high, low]`: the first byte holds the 8 most significative bits of the value and
+    /// the second one the 8 least significative ones, the same order taken by [to_u16](#method.to_u16).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let [high, low] = ByteBase2::from_u16(0xABCD);
+    /// assert_eq!(high, ByteBase2::from_dec(0xAB));
+    /// assert_eq!(low, ByteBase2::from_dec(0xCD));
+    /// ```
+    pub fn from_u16(input: u16) -> [ByteBase2;2] {
+        [ByteBase2::from_dec((input >> 8) as u8), ByteBase2::from_dec(input as u8)]
+    }
+
+    /// Promotes this byte to an i16 value, replicating bit 7 into the 8 high bits.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0xFF).sign_extend_to_i16(), -1);
+    /// assert_eq!(ByteBase2::from_dec(0x7F).sign_extend_to_i16(), 127);
+    /// ```
+    pub fn sign_extend_to_i16(&self) -> i16 {
+        self.as_i8() as i16
+    }
+
+    /// Promotes this byte to an u16 value, filling the 8 high bits with zeros.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0xFF).zero_extend_to_u16(), 255);
+    /// ```
+    pub fn zero_extend_to_u16(&self) -> u16 {
+        self.as_dec() as u16
+    }
+
+    /// Adds one to this byte in place, wrapping 255 around to 0.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(255);
+    /// byte.increment();
+    /// assert_eq!(byte, ByteBase2::from_dec(0));
+    /// ```
+    pub fn increment(&mut self) {
+        *self = self.succ();
+    }
+
+    /// Subtracts one from this byte in place, wrapping 0 around to 255.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0);
+    /// byte.decrement();
+    /// assert_eq!(byte, ByteBase2::from_dec(255));
+    /// ```
+    pub fn decrement(&mut self) {
+        *self = self.pred();
+    }
+
+    /// Returns the byte that follows this one, wrapping 255 around to 0.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(15).succ(), ByteBase2::from_dec(16));
+    /// assert_eq!(ByteBase2::from_dec(255).succ(), ByteBase2::from_dec(0));
+    /// ```
+    pub fn succ(self) -> ByteBase2 {
+        self.wrapping_add(ByteBase2::from_dec(1))
+    }
+
+    /// Returns the byte that precedes this one, wrapping 0 around to 255.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(16).pred(), ByteBase2::from_dec(15));
+    /// assert_eq!(ByteBase2::from_dec(0).pred(), ByteBase2::from_dec(255));
+    /// ```
+    pub fn pred(self) -> ByteBase2 {
+        self.wrapping_sub(ByteBase2::from_dec(1))
+    }
+
+    /// Returns an iterator over every possible byte, from `00000000` to `11111111` in ascending order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::all().count(), 256);
+    /// assert!(ByteBase2::all().zip(0..=255).all(|(byte, value)| byte.as_dec() == value));
+    /// ```
+    pub fn all() -> impl Iterator<Item=ByteBase2> {
+        (0..=255).map(ByteBase2::from_dec)
+    }
+
+    /// Extracts the bits in the given index range, packed into the low bits of an u8 value.
+    /// 
+    /// Index 0 is the least significative bit, so `bits(4..8)` returns the high nibble.
+    /// 
+    /// # Panics
+    /// Panics if the range is out of bounds or decreasing, just like slice indexing.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("11011100").unwrap();
+    /// assert_eq!(byte.bits(2..5), 0b111);
+    /// assert_eq!(byte.bits(4..8), 0b1101);
+    /// ```
+    pub fn bits(&self, range: Range<usize>) -> u8 {
+        self.intern[range].iter().rev().fold(0, |output, bit| output << 1 | *bit as u8)
+    }
+
+    /// Returns the bits of this byte as a vector.
+    /// 
+    /// Yields first the least significative bit and last the most significative one, like
+    /// [iter](#method.iter). Requires the `alloc` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let bits = ByteBase2::from_dec(3).to_bool_vec();
+    /// assert_eq!(bits, vec![true, true, false, false, false, false, false, false]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_bool_vec(&self) -> Vec<bool> {
+        self.intern.to_vec()
+    }
+
+    /// Rotates this byte one bit towards the most significative end through a carry bit.
+    /// 
+    /// The carry takes part in the rotation as a ninth bit: `carry_in` enters at index 0 and the
+    /// bit shifted out of index 7 is returned as the new carry, like the RCL/ROL instructions.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000000").unwrap();
+    /// assert_eq!(byte.rotate_left_through_carry(false), (ByteBase2::from_dec(0), true));
+    /// assert_eq!(ByteBase2::from_dec(0).rotate_left_through_carry(true), (ByteBase2::from_dec(1), false));
+    /// ```
+    pub fn rotate_left_through_carry(self, carry_in: bool) -> (ByteBase2, bool) {
+        let mut output = self << 1_usize;
+        output.intern[0] = carry_in;
+        (output, self.intern[7])
+    }
+
+    /// Rotates this byte one bit towards the least significative end through a carry bit.
+    /// 
+    /// The carry takes part in the rotation as a ninth bit: `carry_in` enters at index 7 and the
+    /// bit shifted out of index 0 is returned as the new carry, like the RCR/ROR instructions.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00000001").unwrap();
+    /// assert_eq!(byte.rotate_right_through_carry(false), (ByteBase2::from_dec(0), true));
+    /// assert_eq!(ByteBase2::from_dec(0).rotate_right_through_carry(true), (ByteBase2::from_dec(128), false));
+    /// ```
+    pub fn rotate_right_through_carry(self, carry_in: bool) -> (ByteBase2, bool) {
+        let mut output = self >> 1_usize;
+        output.intern[7] = carry_in;
+        (output, self.intern[0])
+    }
+
+    /// Returns a byte with the bit order reversed inside each nibble, keeping the nibbles in place.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("00011000").unwrap();
+    /// assert_eq!(byte.reverse_nibbles(), ByteBase2::from_str_ref("10000001").unwrap());
+    /// ```
+    pub fn reverse_nibbles(mut self) -> ByteBase2 {
+        self.intern[..4].reverse();
+        self.intern[4..].reverse();
+        self
+    }
+
+    /// Exchanges the bits at the two given indexes.
+    /// 
+    /// Index 0 access the least significative bit. Swapping an index with itself does nothing.
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if any of the indexes is not lower than 8,
+    /// leaving the byte untouched.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// let mut byte = ByteBase2::from_dec(1);
+    /// assert_eq!(byte.swap_bits(0, 7), Ok(()));
+    /// assert_eq!(byte, ByteBase2::from_dec(128));
+    /// assert_eq!(byte.swap_bits(0, 8), Err(OutOfRange));
+    /// ```
+    pub fn swap_bits(&mut self, i: usize, j: usize) -> Result<(), OutOfRange> {
+        if i >= 8 || j >= 8 { return Err(OutOfRange); }
+        self.intern.swap(i, j);
+        Ok(())
+    }
+
+    /// Returns `true` if the bit pattern of this byte reads the same in both directions.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert!(ByteBase2::from_str_ref("10011001").unwrap().is_palindrome());
+    /// assert!(!ByteBase2::from_str_ref("00000001").unwrap().is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool {
+        *self == self.reverse()
+    }
+
+    /// Returns the bit at the given position counting from the most significative end, or `None` if
+    /// the position is out of range.
+    /// 
+    /// Position 0 is bit 7, the leftmost character of the `Debug` output, and position 7 is bit 0.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_str_ref("10000000").unwrap();
+    /// assert_eq!(byte.bit_from_msb(0), Some(true));
+    /// assert_eq!(byte.bit_from_msb(7), Some(false));
+    /// assert_eq!(byte.bit_from_msb(8), None);
+    /// ```
+    pub fn bit_from_msb(&self, n: usize) -> Option<bool> {
+        7_usize.checked_sub(n).map(|idx| self.intern[idx])
+    }
+
+    /// Returns an iterator over the indexes of the bits set in this byte, in ascending order.
+    /// 
+    /// Index 0 is the least significative bit.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut indices = ByteBase2::from_dec(0b1001).set_bit_indices();
+    /// assert_eq!(indices.next(), Some(0));
+    /// assert_eq!(indices.next(), Some(3));
+    /// assert_eq!(indices.next(), None);
+    /// ```
+    pub fn set_bit_indices(&self) -> impl Iterator<Item=usize> {
+        self.iter().enumerate().filter(|(_, bit)| *bit).map(|(idx, _)| idx)
+    }
+
+    /// Returns the indexes of the bits set in this byte, in ascending order.
+    /// 
+    /// Index 0 is the least significative bit. Requires the `alloc` feature, see
+    /// [set_bit_indices](#method.set_bit_indices) for an allocation free alternative.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b1001).bits_set(), vec![0, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn bits_set(&self) -> Vec<usize> {
+        self.set_bit_indices().collect()
+    }
+
+    /// Returns the index of the lowest bit set, or None if no bit is set.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b00010100).find_first_set(), Some(2));
+    /// assert_eq!(ByteBase2::from_dec(0).find_first_set(), None);
+    /// ```
+    pub fn find_first_set(&self) -> Option<usize> {
+        self.intern.iter().position(|bit| *bit)
+    }
+
+    /// Returns the index of the highest bit set, or None if no bit is set.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(0b00010100).find_last_set(), Some(4));
+    /// assert_eq!(ByteBase2::from_dec(0).find_last_set(), None);
+    /// ```
+    pub fn find_last_set(&self) -> Option<usize> {
+        self.intern.iter().rposition(|bit| *bit)
+    }
+
+    /// Returns the ASCII character represented by this byte.
+    /// 
+    /// Every value in the range 0..=127 is mapped to its ASCII character (control characters
+    /// included), values in the range 128..=255 return None.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(65).as_ascii_char(), Some('A'));
+    /// assert_eq!(ByteBase2::from_dec(200).as_ascii_char(), None);
+    /// ```
+    pub fn as_ascii_char(&self) -> Option<char> {
+        let value = self.as_dec();
+        if value.is_ascii() { Some(value as char) } else { None }
+    }
+
+    /// Creates a ByteBase2 from the code point of the given character.
+    /// 
+    /// Accepts every character whose code point is in the range 0..=255 (U+0000 to U+00FF),
+    /// which covers ASCII and the Latin-1 supplement.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if the code point of the character
+    /// doesn't fit in a byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_ascii_char('A').unwrap(), ByteBase2::from_dec(65));
+    /// assert!(ByteBase2::from_ascii_char('€').is_err());
+    /// ```
+    pub fn from_ascii_char(c: char) -> Result<Self, InvalidPattern> {
+        u8::try_from(c).map(ByteBase2::from_dec).map_err(|_| InvalidPattern)
+    }
+
+    /// Converts this byte to its binary string, inserting a separator every `group` bits counted
+    /// from the least significative bit.
+    /// 
+    /// A `group` of 0 or greater than or equal to 8 produces the ungrouped string. Requires the
+    /// `alloc` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let byte = ByteBase2::from_dec(15);
+    /// assert_eq!(byte.to_grouped_string(4, '_'), "0000_1111");
+    /// assert_eq!(byte.to_grouped_string(3, ' '), "00 001 111");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_grouped_string(&self, group: usize, sep: char) -> String {
+        let mut output = String::with_capacity(15);
+        for (idx, bit) in self.intern.iter().enumerate().rev() {
+            if group != 0 && idx != 7 && (idx + 1) % group == 0 { output.push(sep); }
+            output.push(if *bit { '1' } else { '0' });
+        }
+        output
+    }
+
+    /// Converts this byte to its shortest binary string, without leading zeros.
+    /// 
+    /// A zero byte is represented as "0". Requires the `alloc` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(5).to_compact_string(), "101");
+    /// assert_eq!(ByteBase2::from_dec(0).to_compact_string(), "0");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_compact_string(&self) -> String {
+        alloc::format!("{:b}", self.as_dec())
+    }
+
+    /// Returns the smallest of two bytes, comparing their unsigned decimal values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(5).min(ByteBase2::from_dec(9)), ByteBase2::from_dec(5));
+    /// ```
+    pub fn min(self, other: ByteBase2) -> ByteBase2 {
+        Ord::min(self, other)
+    }
+
+    /// Returns the greatest of two bytes, comparing their unsigned decimal values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// assert_eq!(ByteBase2::from_dec(5).max(ByteBase2::from_dec(9)), ByteBase2::from_dec(9));
+    /// ```
+    pub fn max(self, other: ByteBase2) -> ByteBase2 {
+        Ord::max(self, other)
+    }
+
+    /// Restricts this byte to the interval between `low` and `high`, comparing their unsigned
+    /// decimal values.
+    /// 
+    /// # Panics
+    /// Panics if `low` is greater than `high`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let (low, high) = (ByteBase2::from_dec(3), ByteBase2::from_dec(10));
+    /// assert_eq!(ByteBase2::from_dec(15).clamp(low, high), ByteBase2::from_dec(10));
+    /// assert_eq!(ByteBase2::from_dec(1).clamp(low, high), ByteBase2::from_dec(3));
+    /// assert_eq!(ByteBase2::from_dec(7).clamp(low, high), ByteBase2::from_dec(7));
+    /// ```
+    pub fn clamp(self, low: ByteBase2, high: ByteBase2) -> ByteBase2 {
+        Ord::clamp(self, low, high)
+    }
+
+    /// Returns true if every bit set in the mask is also set in this byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let flags = ByteBase2::from_dec(0b1110);
+    /// assert!(flags.contains(&ByteBase2::from_dec(0b0110)));
+    /// assert!(!flags.contains(&ByteBase2::from_dec(0b0011)));
+    /// ```
+    pub fn contains(&self, mask: &ByteBase2) -> bool {
+        self.intern.iter().zip(mask.intern.iter()).all(|(bit, mask)| *bit || !*mask)
+    }
+
+    /// Returns true if this byte and the mask have at least one set bit in common.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let flags = ByteBase2::from_dec(0b1110);
+    /// assert!(flags.intersects(&ByteBase2::from_dec(0b0011)));
+    /// assert!(!flags.intersects(&ByteBase2::from_dec(0b0001)));
+    /// ```
+    pub fn intersects(&self, mask: &ByteBase2) -> bool {
+        self.intern.iter().zip(mask.intern.iter()).any(|(bit, mask)| *bit && *mask)
+    }
+
+    /// Returns this byte with every bit set in the mask cleared.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let flags = ByteBase2::from_dec(0b1110);
+    /// assert_eq!(flags.difference(ByteBase2::from_dec(0b0011)), ByteBase2::from_dec(0b1100));
+    /// ```
+    pub fn difference(self, mask: ByteBase2) -> ByteBase2 {
+        self & !mask
+    }
+
+    /// Sets every bit of this byte to 1.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0b1010);
+    /// byte.set_all();
+    /// assert_eq!(byte, ByteBase2::from_dec(255));
+    /// ```
+    pub fn set_all(&mut self) {
+        self.intern = [true;8];
+    }
+
+    /// Sets every bit of this byte to 0.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0b1010);
+    /// byte.clear_all();
+    /// assert_eq!(byte, ByteBase2::from_dec(0));
+    /// ```
+    pub fn clear_all(&mut self) {
+        self.intern = [false;8];
+    }
+
+    /// Flips every bit of this byte in place.
+    /// 
+    /// Equivalent to assigning the result of the `!` operator to this byte.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut byte = ByteBase2::from_dec(0b00001111);
+    /// byte.toggle_all();
+    /// assert_eq!(byte, ByteBase2::from_dec(0b11110000));
+    /// ```
+    pub fn toggle_all(&mut self) {
+        *self = !*self;
+    }
+
+    /// Creates a ByteBase2 with the bits at the given indexes set to one and the others set to zero.
+    /// 
+    /// Index 0 access the least significative bit. Repeated indexes are allowed. This is the inverse
+    /// of [set_bit_indices](#method.set_bit_indices).
+    /// 
+    /// # Errors
+    /// Returns an Err([OutOfRange](struct.OutOfRange.html)) if any of the indexes is not lower than 8.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, OutOfRange };
+    /// 
+    /// assert_eq!(ByteBase2::from_set_indices(vec![0, 3]), Ok(ByteBase2::from_dec(0b1001)));
+    /// assert_eq!(ByteBase2::from_set_indices(vec![0, 8]), Err(OutOfRange));
+    /// ```
+    pub fn from_set_indices(indices: impl IntoIterator<Item=usize>) -> Result<Self, OutOfRange> {
+        let mut output = ByteBase2::from_dec(0);
+        for idx in indices {
+            output.set_bit(idx)?;
+        }
+        Ok(output)
+    }
+
+    /// Returns the names of the bits set in this byte, in ascending order of their indexes.
+    /// 
+    /// `names[0]` names the least significative bit. Requires the `alloc` feature.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let names = ["CF", "ZF", "IF", "DF", "OF", "SF", "TF", "AF"];
+    /// assert_eq!(ByteBase2::from_dec(0b101).describe_flags(&names), vec!["CF", "IF"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn describe_flags<'a>(&self, names: &[&'a str; 8]) -> Vec<&'a str> {
+        self.set_bit_indices().map(|idx| names[idx]).collect()
+    }
+
+    /// Writes this byte as a single octet to the given writer.
+    /// 
+    /// Requires the `std` feature.
+    /// 
+    /// # Errors
+    /// Returns any error reported by the writer.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut buffer = Vec::new();
+    /// ByteBase2::from_dec(15).write_to(&mut buffer).unwrap();
+    /// ByteBase2::from_dec(240).write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, vec![15, 240]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        w.write_all(&[self.as_dec()])
+    }
+
+    /// Reads exactly one octet from the given reader and creates a ByteBase2 from it.
+    /// 
+    /// Requires the `std` feature.
+    /// 
+    /// # Errors
+    /// Returns an error of kind `UnexpectedEof` if the reader has no byte available, or any other
+    /// error reported by the reader.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// 
+    /// let mut reader: &[u8] = &[15, 240];
+    /// assert_eq!(ByteBase2::read_from(&mut reader).unwrap(), ByteBase2::from_dec(15));
+    /// assert_eq!(ByteBase2::read_from(&mut reader).unwrap(), ByteBase2::from_dec(240));
+    /// assert!(ByteBase2::read_from(&mut reader).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_from<R: std::io::Read>(r: &mut R) -> std::io::Result<ByteBase2> {
+        let mut buffer = [0_u8];
+        r.read_exact(&mut buffer)?;
+        Ok(ByteBase2::from_dec(buffer[0]))
+    }
+
+    /// Creates a ByteBase2 from an array of digits, each one being 0 or 1.
+    /// 
+    /// Index 0 of the array holds the least significative bit, matching the byte's own indexing.
+    /// 
+    /// # Errors
+    /// Returns an Err([InvalidPattern](struct.InvalidPattern.html)) if any of the digits is not 0 or 1.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::{ ByteBase2, InvalidPattern };
+    /// 
+    /// assert_eq!(ByteBase2::from_digits([1, 1, 0, 0, 0, 0, 0, 0]), Ok(ByteBase2::from_dec(3)));
+    /// assert_eq!(ByteBase2::from_digits([2, 0, 0, 0, 0, 0, 0, 0]), Err(InvalidPattern));
+    /// ```
+    pub fn from_digits(digits: [u8;8]) -> Result<Self, InvalidPattern> {
+        let mut intern = [false;8];
+        for (bit, digit) in intern.iter_mut().zip(digits.iter()) {
+            *bit = match digit {
+                0 => false,
+                1 => true,
+                _ => return Err(InvalidPattern)
+            };
+        }
+        Ok(ByteBase2 { intern })
+    }
+
+    /// Compares two bytes by their two's complement value, where bit 7 is the sign.
+    /// 
+    /// Unlike the `Ord` implementation, which compares the unsigned decimal values,
+    /// `11111111` (-1) is less than `00000001` (1).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::ByteBase2;
+    /// use std::cmp::Ordering;
+    /// 
+    /// let (minus_one, one) = (ByteBase2::from_dec(0xFF), ByteBase2::from_dec(1));
+    /// assert_eq!(minus_one.cmp_signed(&one), Ordering::Less);
+    /// assert_eq!(minus_one.cmp(&one), Ordering::Greater);
+    /// ```
+    pub fn cmp_signed(&self, other: &ByteBase2) -> Ordering {
+        self.as_i8().cmp(&other.as_i8())
+    }
+}
+
+impl BitsBase2<16> {
+    /// Creates a 16 bit word from an u16 value.
+    /// 
+    /// Can be used in constant contexts.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::BitsBase2;
+    /// 
+    /// let word = BitsBase2::<16>::from_dec(256);
+    /// assert_eq!(word[8], true);
+    /// assert_eq!(word.ones(), 1);
+    /// ```
+    pub const fn from_dec(input: u16) -> Self {
+        Self::from_u32(input as u32)
+    }
+
+    /// Converts a 16 bit word to its decimal representation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::BitsBase2;
+    /// 
+    /// let word = BitsBase2::<16>::from_dec(1000);
+    /// assert_eq!(word.as_dec(), 1000);
+    /// ```
+    pub const fn as_dec(&self) -> u16 {
+        self.as_u32() as u16
+    }
+}
+
+impl BitsBase2<32> {
+    /// Creates a 32 bit word from an u32 value.
+    /// 
+    /// Can be used in constant contexts.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::BitsBase2;
+    /// 
+    /// let word = BitsBase2::<32>::from_dec(256);
+    /// assert_eq!(word[8], true);
+    /// assert_eq!(word.ones(), 1);
+    /// ```
+    pub const fn from_dec(input: u32) -> Self {
+        Self::from_u32(input)
+    }
+
+    /// Converts a 32 bit word to its decimal representation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use binary_byte::BitsBase2;
+    /// 
+    /// let word = BitsBase2::<32>::from_dec(1000);
+    /// assert_eq!(word.as_dec(), 1000);
+    /// ```
+    pub const fn as_dec(&self) -> u32 {
+        self.as_u32()
+    }
+}
+
+/// Access these bits.
+/// 
+/// Index 0 access the least significative bit.
+impl<const N: usize> Index<usize> for BitsBase2<N> {
+    type Output = bool;
+
+    fn index(&self, idx: usize) -> &Self::Output {
+        &self.intern[idx]
+    }
+}
+
+/// Mutably access these bits.
+/// 
+/// Index 0 access the least significative bit.
+impl<const N: usize> IndexMut<usize> for BitsBase2<N> {
+    fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
+        &mut self.intern[idx]
+    }
+}
+
+/// Access the bits in this byte by their name.
+/// 
+/// See also [Bit](enum.Bit.html).
+impl Index<Bit> for ByteBase2 {
+    type Output = bool;
+
+    fn index(&self, bit: Bit) -> &Self::Output {
+        &self.intern[bit as usize]
+    }
+}
+
+/// Mutably access the bits in this byte by their name.
+/// 
+/// See also [Bit](enum.Bit.html).
+impl IndexMut<Bit> for ByteBase2 {
+    fn index_mut(&mut self, bit: Bit) -> &mut Self::Output {
+        &mut self.intern[bit as usize]
+    }
+}
+
+/// Performs the bitwise AND between two bytes.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(0b1100) & ByteBase2::from_dec(0b1010);
+/// assert_eq!(byte, ByteBase2::from_dec(0b1000));
+/// ```
+impl BitAnd for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+impl BitAnd for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        *self & *rhs
+    }
+}
+
+/// Performs the bitwise AND between a byte and an u8 mask.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(0xFF) & 0x0F_u8, ByteBase2::from_dec(0x0F));
+/// ```
+impl BitAnd<u8> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitand(self, rhs: u8) -> Self::Output {
+        self & ByteBase2::from_dec(rhs)
+    }
+}
+
+/// Performs the bitwise OR between two bytes.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(0b1100) | ByteBase2::from_dec(0b0011);
+/// assert_eq!(byte, ByteBase2::from_dec(0b1111));
+/// ```
+impl BitOr for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+impl BitOr for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        *self | *rhs
+    }
+}
+
+/// Performs the bitwise OR between a byte and an u8 mask.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(0x0F) | 0xF0_u8, ByteBase2::from_dec(0xFF));
+/// ```
+impl BitOr<u8> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitor(self, rhs: u8) -> Self::Output {
+        self | ByteBase2::from_dec(rhs)
+    }
+}
+
+/// Performs the bitwise XOR between two bytes.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(0b1100) ^ ByteBase2::from_dec(0b1010);
+/// assert_eq!(byte, ByteBase2::from_dec(0b0110));
+/// ```
+impl BitXor for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+impl BitXor for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        *self ^ *rhs
+    }
+}
+
+/// Performs the bitwise XOR between a byte and an u8 mask.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(0x0F) ^ 0xFF_u8, ByteBase2::from_dec(0xF0));
+/// ```
+impl BitXor<u8> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn bitxor(self, rhs: u8) -> Self::Output {
+        self ^ ByteBase2::from_dec(rhs)
+    }
+}
+
+/// Flips every bit of this byte.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(!ByteBase2::from_dec(0b00001111), ByteBase2::from_dec(0b11110000));
+/// ```
+impl Not for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn not(mut self) -> Self::Output {
+        for bit in self.intern.iter_mut() {
+            *bit = !*bit;
+        }
+        self
+    }
+}
+
+impl Not for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn not(self) -> Self::Output {
+        !*self
+    }
+}
+
+/// Adds two bytes, wrapping around at the boundary of the type.
+/// 
+/// Unlike `u8`, the sum never panics on overflow, it behaves like
+/// [ByteBase2::wrapping_add](struct.BitsBase2.html#method.wrapping_add) in every build profile.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(1) + ByteBase2::from_dec(2), ByteBase2::from_dec(3));
+/// assert_eq!(ByteBase2::from_dec(255) + ByteBase2::from_dec(1), ByteBase2::from_dec(0));
+/// ```
+impl Add for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.wrapping_add(rhs)
+    }
+}
+
+impl Add for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+/// Subtracts two bytes, wrapping around at the boundary of the type.
+/// 
+/// Unlike `u8`, the difference never panics on underflow, it behaves like
+/// [ByteBase2::wrapping_sub](struct.BitsBase2.html#method.wrapping_sub) in every build profile.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(5) - ByteBase2::from_dec(3), ByteBase2::from_dec(2));
+/// assert_eq!(ByteBase2::from_dec(0) - ByteBase2::from_dec(1), ByteBase2::from_dec(255));
+/// ```
+impl Sub for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.wrapping_sub(rhs)
+    }
+}
+
+impl Sub for &ByteBase2 {
+    type Output = ByteBase2;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+/// Performs the bitwise AND between two bytes, storing the result in the left operand.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let mut byte = ByteBase2::from_dec(0b1100);
+/// byte &= ByteBase2::from_dec(0b1010);
+/// assert_eq!(byte, ByteBase2::from_dec(0b1000));
+/// ```
+impl BitAndAssign for ByteBase2 {
+    fn bitand_assign(&mut self, rhs: Self) {
+        for (bit, other) in self.intern.iter_mut().zip(rhs.intern.iter()) {
+            *bit &= *other;
+        }
+    }
+}
+
+/// Performs the bitwise OR between two bytes, storing the result in the left operand.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let mut byte = ByteBase2::from_dec(0b1100);
+/// byte |= ByteBase2::from_dec(0b0011);
+/// assert_eq!(byte, ByteBase2::from_dec(0b1111));
+/// ```
+impl BitOrAssign for ByteBase2 {
+    fn bitor_assign(&mut self, rhs: Self) {
+        for (bit, other) in self.intern.iter_mut().zip(rhs.intern.iter()) {
+            *bit |= *other;
+        }
+    }
+}
+
+/// Performs the bitwise XOR between two bytes, storing the result in the left operand.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let mut byte = ByteBase2::from_dec(0b1100);
+/// byte ^= ByteBase2::from_dec(0b1010);
+/// assert_eq!(byte, ByteBase2::from_dec(0b0110));
+/// ```
+impl BitXorAssign for ByteBase2 {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        for (bit, other) in self.intern.iter_mut().zip(rhs.intern.iter()) {
+            *bit ^= *other;
+        }
+    }
+}
+
+/// Shifts the bits of this byte towards the most significative end.
+/// 
+/// The vacated low bits are filled with zeros and the bits shifted past index 7 are discarded,
+/// so shifting by 8 or more yields an all-zero byte.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(1) << 3_usize, ByteBase2::from_dec(8));
+/// assert_eq!(ByteBase2::from_dec(1) << 3_u8, ByteBase2::from_dec(8));
+/// assert_eq!(ByteBase2::from_dec(0xFF) << 8_u32, ByteBase2::from_dec(0));
+/// ```
+impl Shl<usize> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shl(self, rhs: usize) -> Self::Output {
+        let mut intern = [false;8];
+        for (bit, shifted) in intern.iter_mut().skip(rhs).zip(self.intern.iter()) {
+            *bit = *shifted;
+        }
+        ByteBase2 { intern }
+    }
+}
+
+impl Shl<u32> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        self << rhs as usize
+    }
+}
+
+impl Shl<u8> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shl(self, rhs: u8) -> Self::Output {
+        self << rhs as usize
+    }
+}
+
+/// Shifts the bits of this byte towards the least significative end.
+/// 
+/// This is a logical shift: the vacated high bits are filled with zeros and the bits shifted past
+/// index 0 are discarded, so shifting by 8 or more yields an all-zero byte.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(8) >> 3_usize, ByteBase2::from_dec(1));
+/// assert_eq!(ByteBase2::from_dec(8) >> 3_u8, ByteBase2::from_dec(1));
+/// assert_eq!(ByteBase2::from_dec(0xFF) >> 8_u32, ByteBase2::from_dec(0));
+/// ```
+impl Shr<usize> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shr(self, rhs: usize) -> Self::Output {
+        let mut intern = [false;8];
+        for (bit, shifted) in intern.iter_mut().zip(self.intern.iter().skip(rhs)) {
+            *bit = *shifted;
+        }
+        ByteBase2 { intern }
+    }
+}
+
+impl Shr<u32> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        self >> rhs as usize
+    }
+}
+
+impl Shr<u8> for ByteBase2 {
+    type Output = ByteBase2;
+
+    fn shr(self, rhs: u8) -> Self::Output {
+        self >> rhs as usize
+    }
+}
+
+/// Creates a ByteBase2 object from an u8 value.
+/// 
+/// See also [ByteBase2::from_dec](struct.BitsBase2.html#method.from_dec).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte: ByteBase2 = 15_u8.into();
+/// assert_eq!(byte, ByteBase2::from_dec(15));
+/// ```
+impl From<u8> for ByteBase2 {
+    fn from(input: u8) -> Self {
+        ByteBase2::from_dec(input)
+    }
+}
+
+/// Creates a BitsBase2 object from an array of bits.
+/// 
+/// Index 0 of the array holds the least significative bit, matching the value's own indexing.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from([true, false, false, false, false, false, false, false]);
+/// assert_eq!(byte, ByteBase2::from_dec(1));
+/// ```
+impl<const N: usize> From<[bool;N]> for BitsBase2<N> {
+    fn from(intern: [bool;N]) -> Self {
+        BitsBase2 { intern }
+    }
+}
+
+/// Converts a ByteBase2 number to its decimal representation.
+/// 
+/// See also [ByteBase2::as_dec](struct.BitsBase2.html#method.as_dec).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let value: u8 = ByteBase2::from_dec(15).into();
+/// assert_eq!(value, 15);
+/// ```
+impl From<ByteBase2> for u8 {
+    fn from(input: ByteBase2) -> Self {
+        input.as_dec()
+    }
+}
+
+/// Converts a ByteBase2 number to its 8 bit binary string, the same one written by `Debug`.
+/// 
+/// Requires the `alloc` feature.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let pattern: String = ByteBase2::from_dec(15).into();
+/// assert_eq!(pattern, "00001111");
+/// ```
+#[cfg(feature = "alloc")]
+impl From<ByteBase2> for String {
+    fn from(input: ByteBase2) -> Self {
+        String::from(&input)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&ByteBase2> for String {
+    fn from(input: &ByteBase2) -> Self {
+        alloc::format!("{:?}", input)
+    }
+}
+
+/// Tries to create a ByteBase2 from a string representing an 8 bit binary number.
+/// 
+/// See also [ByteBase2::from_str_ref](struct.BitsBase2.html#method.from_str_ref).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use std::convert::TryFrom;
+/// use binary_byte::{ ByteBase2, InvalidPattern };
+/// 
+/// assert_eq!(ByteBase2::try_from("01010101"), Ok(ByteBase2::from_dec(85)));
+/// assert_eq!(ByteBase2::try_from("bad"), Err(InvalidPattern));
+/// ```
+impl TryFrom<&str> for ByteBase2 {
+    type Error = InvalidPattern;
+
+    fn try_from(pattern: &str) -> Result<Self, Self::Error> {
+        ByteBase2::from_str_ref(pattern)
+    }
+}
+
+/// Parses a ByteBase2 from a string representing an 8 bit binary number.
+/// 
+/// See also [ByteBase2::from_str_ref](struct.BitsBase2.html#method.from_str_ref).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, InvalidPattern };
+/// 
+/// assert_eq!("11110000".parse::<ByteBase2>(), Ok(ByteBase2::from_dec(240)));
+/// assert_eq!("bad".parse::<ByteBase2>(), Err(InvalidPattern));
+/// ```
+impl FromStr for ByteBase2 {
+    type Err = InvalidPattern;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        ByteBase2::from_str_ref(pattern)
+    }
+}
+
+/// Iterates over these bits.
+/// 
+/// Yields first the least significative bit and last the most significative one, just like
+/// [ByteBase2::iter](struct.BitsBase2.html#method.iter).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let mut ones = 0;
+/// for bit in ByteBase2::from_dec(15) {
+///     if bit { ones += 1; }
+/// }
+/// assert_eq!(ones, 4);
+/// ```
+impl<const N: usize> IntoIterator for BitsBase2<N> {
+    type Item = bool;
+    type IntoIter = Iter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> IntoIterator for &BitsBase2<N> {
+    type Item = bool;
+    type IntoIter = Iter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collects bits into a value of N bits.
+/// 
+/// The first item becomes the least significative bit and the Nth one the most significative.
+/// If the iterator yields fewer than N items the remaining high bits are set to zero, and any
+/// item after the Nth is ignored.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte: ByteBase2 = vec![true, true, false, false, false, false, false, false].into_iter().collect();
+/// assert_eq!(byte, ByteBase2::from_dec(3));
+/// ```
+impl<const N: usize> FromIterator<bool> for BitsBase2<N> {
+    fn from_iter<I: IntoIterator<Item=bool>>(iter: I) -> Self {
+        let mut intern = [false;N];
+        for (bit, item) in intern.iter_mut().zip(iter) {
+            *bit = item;
+        }
+        BitsBase2 { intern }
+    }
+}
+
+/// Compares two bytes by their unsigned decimal value.
+/// 
+/// Bit 7 is not treated as a sign, so `10000000` (128) is greater than `01111111` (127).
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert!(ByteBase2::from_dec(1) < ByteBase2::from_dec(2));
+/// assert!(ByteBase2::from_dec(128) > ByteBase2::from_dec(127));
+/// ```
+impl Ord for ByteBase2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_dec().cmp(&other.as_dec())
+    }
+}
+
+impl PartialOrd for ByteBase2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares a byte with a plain `u8` by its decimal value.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// assert_eq!(ByteBase2::from_dec(15), 15_u8);
+/// assert_eq!(15_u8, ByteBase2::from_dec(15));
+/// ```
+impl PartialEq<u8> for ByteBase2 {
+    fn eq(&self, other: &u8) -> bool {
+        self.as_dec() == *other
+    }
+}
+
+impl PartialEq<ByteBase2> for u8 {
+    fn eq(&self, other: &ByteBase2) -> bool {
+        *self == other.as_dec()
+    }
+}
+
+/// Steps between bytes in ascending decimal order, allowing ranges of bytes to be iterated.
+/// 
+/// Requires the `step_trait` feature, which is only available on nightly compilers.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let bytes: Vec<ByteBase2> = (ByteBase2::from_dec(1)..ByteBase2::from_dec(4)).collect();
+/// assert_eq!(bytes, vec![ByteBase2::from_dec(1), ByteBase2::from_dec(2), ByteBase2::from_dec(3)]);
+/// ```
+#[cfg(feature = "step_trait")]
+impl core::iter::Step for ByteBase2 {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        u8::steps_between(&start.as_dec(), &end.as_dec())
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        u8::forward_checked(start.as_dec(), count).map(ByteBase2::from_dec)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        u8::backward_checked(start.as_dec(), count).map(ByteBase2::from_dec)
+    }
+}
+
+/// Formats these bits as a binary string, most significative bit first.
+/// 
+/// The alternate flag (`{:#?}`) also writes the unsigned decimal value between parentheses.
+/// 
+/// The decimal value is computed through an `u128`, so it is only written for N of up to 128:
+/// wider words print just their bits, even with the alternate flag.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ BitsBase2, ByteBase2 };
+/// 
+/// let byte = ByteBase2::from_dec(15);
+/// assert_eq!(format!("{:?}", byte), "00001111".to_string());
+/// assert_eq!(format!("{:#?}", byte), "00001111 (15)".to_string());
+/// 
+/// let wide = BitsBase2::from([true;129]);
+/// assert_eq!(format!("{:#?}", wide), "1".repeat(129));
+/// ```
+impl<const N: usize> Debug for BitsBase2<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for bit in self.intern.iter().rev() {
+            if *bit { f.write_char('1')?; }
+            else { f.write_char('0')?; }
+        }
+        if f.alternate() && N <= 128 {
+            let value = self.intern.iter().rev().fold(0_u128, |value, bit| value << 1 | *bit as u128);
+            write!(f, " ({})", value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats these bits as a binary string, most significative bit first.
+/// 
+/// The alternate flag (`{:#}`) prefixes the output with `0b`. Width, fill, alignment and zero
+/// padding are honored like for the integer types, so the precision is ignored.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(15);
+/// assert_eq!(format!("{}", byte), "00001111".to_string());
+/// assert_eq!(format!("{:#}", byte), "0b00001111".to_string());
+/// assert_eq!(format!("{:>10}", byte), "  00001111".to_string());
+/// ```
+impl<const N: usize> Display for BitsBase2<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad_integral(true, "0b", core::str::from_utf8(&self.binary_digits()).unwrap())
+    }
+}
+
+/// Formats this byte as a 2 digit lowercase hexadecimal number.
+/// 
+/// The alternate flag (`{:#x}`) prefixes the output with `0x`. Width, fill, alignment and zero
+/// padding are honored like for the integer types.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(15);
+/// assert_eq!(format!("{:x}", byte), "0f".to_string());
+/// assert_eq!(format!("{:#x}", byte), "0x0f".to_string());
+/// assert_eq!(format!("{:#06x}", byte), "0x000f".to_string());
+/// ```
+impl LowerHex for ByteBase2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (table, value) = (b"0123456789abcdef", self.as_dec());
+        let digits = [table[(value >> 4) as usize], table[(value & 0x0F) as usize]];
+        f.pad_integral(true, "0x", core::str::from_utf8(&digits).unwrap())
+    }
+}
+
+/// Formats this byte as a 2 digit uppercase hexadecimal number.
+/// 
+/// The alternate flag (`{:#X}`) prefixes the output with `0x`, just like the integer types do.
+/// Width, fill, alignment and zero padding are honored too.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(255);
+/// assert_eq!(format!("{:X}", byte), "FF".to_string());
+/// assert_eq!(format!("{:#X}", byte), "0xFF".to_string());
+/// assert_eq!(format!("{:>4X}", byte), "  FF".to_string());
+/// ```
+impl UpperHex for ByteBase2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (table, value) = (b"0123456789ABCDEF", self.as_dec());
+        let digits = [table[(value >> 4) as usize], table[(value & 0x0F) as usize]];
+        f.pad_integral(true, "0x", core::str::from_utf8(&digits).unwrap())
+    }
+}
+
+/// Formats this byte as its 8 bit binary string.
+/// 
+/// The alternate flag (`{:#b}`) prefixes the output with `0b`. Width, fill, alignment and zero
+/// padding are honored like for the integer types.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(5);
+/// assert_eq!(format!("{:b}", byte), "00000101".to_string());
+/// assert_eq!(format!("{:#b}", byte), "0b00000101".to_string());
+/// assert_eq!(format!("{:>10b}", byte), "  00000101".to_string());
+/// ```
+impl Binary for ByteBase2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad_integral(true, "0b", core::str::from_utf8(&self.binary_digits()).unwrap())
+    }
+}
+
+/// Formats this byte as an octal number with up to 3 digits.
+/// 
+/// The alternate flag (`{:#o}`) prefixes the output with `0o`. Every other format specifier
+/// behaves exactly like for `u8`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// 
+/// let byte = ByteBase2::from_dec(8);
+/// assert_eq!(format!("{:o}", byte), "10".to_string());
+/// assert_eq!(format!("{:#o}", byte), "0o10".to_string());
+/// assert_eq!(format!("{:6o}", byte), "    10".to_string());
+/// ```
+impl Octal for ByteBase2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Octal::fmt(&self.as_dec(), f)
+    }
+}
+
+/// Creates a ByteBase2 from an 8 digit binary literal at compile time.
+/// 
+/// Accepts either a numeric literal, like `bb!(0000_1111)`, or a string literal, like `bb!("00001111")`,
+/// with exactly 8 '0' or '1' digits, optionally separated by underscores. The byte is built in a
+/// constant context, so a malformed literal fails the build instead of panicking at runtime.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ bb, ByteBase2 };
+/// 
+/// assert_eq!(bb!(0000_1111), ByteBase2::from_dec(15));
+/// assert_eq!(bb!("11110000"), ByteBase2::from_dec(240));
+/// ```
+/// 
+/// ```compile_fail
+/// use binary_byte::bb;
+/// 
+/// let byte = bb!(0000_1112);
+/// ```
+#[macro_export]
+macro_rules! bb {
+    ($literal:literal) => {{
+        const BYTE: $crate::ByteBase2 = $crate::__parse_literal(stringify!($literal));
+        BYTE
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __parse_literal(literal: &str) -> ByteBase2 {
+    let digits = literal.as_bytes();
+    let (mut start, mut end) = (0, digits.len());
+    if end >= 2 && digits[0] == b'"' && digits[end - 1] == b'"' {
+        start += 1;
+        end -= 1;
+    }
+    let mut intern = [false;8];
+    let mut count = 0;
+    while end > start {
+        end -= 1;
+        match digits[end] {
+            b'_' => {},
+            b'0' | b'1' if count < 8 => {
+                intern[count] = digits[end] == b'1';
+                count += 1;
+            },
+            _ => panic!("The literal should have exactly 8, '0' or '1', digits.")
+        }
+    }
+    if count != 8 { panic!("The literal should have exactly 8, '0' or '1', digits."); }
+    ByteBase2 { intern }
+}
+
+/// Folds every byte of the slice together with the bitwise XOR.
+/// 
+/// Returns a zero byte for an empty slice, so appending the checksum to the data and
+/// checksumming it again always yields zero.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, xor_checksum };
+/// 
+/// let data = [ByteBase2::from_dec(0x0F), ByteBase2::from_dec(0xF0)];
+/// assert_eq!(xor_checksum(&data), ByteBase2::from_dec(0xFF));
+/// ```
+pub fn xor_checksum(bytes: &[ByteBase2]) -> ByteBase2 {
+    bytes.iter().fold(ByteBase2::from_dec(0), |checksum, byte| checksum ^ *byte)
+}
+
+/// Returns how many ones there is in all the bytes of the slice.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, total_ones };
+/// 
+/// let data = [ByteBase2::from_dec(0xFF), ByteBase2::from_dec(0x0F)];
+/// assert_eq!(total_ones(&data), 12);
+/// ```
+pub fn total_ones(bytes: &[ByteBase2]) -> usize {
+    bytes.iter().map(ByteBase2::ones).sum()
+}
+
+/// Returns the fraction of set bits in all the bytes of the slice, from 0.0 to 1.0.
+/// 
+/// An empty slice has a density of 0.0.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::{ ByteBase2, bit_density };
+/// 
+/// let data = [ByteBase2::from_dec(0xFF), ByteBase2::from_dec(0x00)];
+/// assert_eq!(bit_density(&data), 0.5);
+/// ```
+pub fn bit_density(bytes: &[ByteBase2]) -> f64 {
+    if bytes.is_empty() { return 0.0; }
+    total_ones(bytes) as f64 / (bytes.len() * 8) as f64
+}
+
+/// Serializes this byte as its 8 bit binary string.
+/// 
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteBase2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a byte from its 8 bit binary string.
+/// 
+/// Requires the `serde` feature. Strings rejected by
+/// [ByteBase2::from_string](struct.BitsBase2.html#method.from_string) are reported as invalid values.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteBase2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PatternVisitor;
+
+        impl serde::de::Visitor<'_> for PatternVisitor {
+            type Value = ByteBase2;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(f, "a string with exactly 8, '0' or '1', characters")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, pattern: &str) -> Result<Self::Value, E> {
+                ByteBase2::from_str_ref(pattern)
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(pattern), &self))
+            }
+        }
+
+        deserializer.deserialize_str(PatternVisitor)
+    }
+}
+
+/// Generates uniformly distributed random bytes.
+/// 
+/// Requires the `rand` feature.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use binary_byte::ByteBase2;
+/// use rand::{ Rng, SeedableRng, rngs::StdRng };
+/// 
+/// let (mut rng, mut same_seed) = (StdRng::seed_from_u64(42), StdRng::seed_from_u64(42));
+/// let byte: ByteBase2 = rng.gen();
+/// assert_eq!(byte, ByteBase2::from_dec(same_seed.gen::<u8>()));
+/// ```
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<ByteBase2> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ByteBase2 {
+        ByteBase2::from_dec(rng.gen())
+    }
+}
+
+#[cfg(all(test, not(feature = "std")))]
+mod no_std_test_mod {
+    use crate::ByteBase2;
+
+    #[test]
+    fn core_api_test() {
+        let mut byte = ByteBase2::from_dec(15);
+        assert_eq!(byte.as_dec(), 15);
+        assert_eq!(byte.ones(), 4);
+        assert!(byte[0]);
+        byte[7] = true;
+        assert_eq!(byte.as_dec(), 143);
+        assert_eq!(byte.iter().filter(|bit| *bit).count(), 5);
+        assert_eq!(ByteBase2::from_str_ref("10001111"), Ok(byte));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test_mod {
+    use crate::{ Bit, BitsBase2, ByteBase2, InvalidBcd, InvalidHex, InvalidPattern, OutOfRange, xor_checksum, total_ones, bit_density };
+
+    #[test]
+    #[should_panic]
+    fn index_test() {
+        let byte = ByteBase2::from_dec(15);
+        #[allow(clippy::no_effect)]
+        byte[8];
+    }
+
+    #[test]
+    fn copy_test() {
+        let byte = ByteBase2::from_dec(15);
+        let mut copy = byte;
+        copy[7] = true;
+        assert_eq!(byte.as_dec(), 15);
+        assert_eq!(copy.as_dec(), 143);
+    }
+
+    #[test]
+    fn copy_equality_test() {
+        let byte = ByteBase2::from_dec(15);
+        let (mut first, second) = (byte, byte);
+        assert_eq!(first, second);
+        first[0] = false;
+        assert_ne!(first, second);
+        assert_eq!(second, ByteBase2::from_dec(15));
+    }
+
+    #[test]
+    fn debug_test() {
+        assert_eq!(format!("{:?}", ByteBase2::from_dec(15)), "00001111".to_string());
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(format!("{}", ByteBase2::from_dec(15)), format!("{:?}", ByteBase2::from_dec(15)));
+        assert_eq!(format!("{:#}", ByteBase2::from_dec(15)), "0b00001111".to_string());
+        assert_eq!(format!("{}", ByteBase2::from_dec(0)), "00000000".to_string());
+        assert_eq!(format!("{:>12}", ByteBase2::from_dec(8)), "    00001000".to_string());
+        assert_eq!(format!("{:<12}|", ByteBase2::from_dec(8)), "00001000    |".to_string());
+        assert_eq!(format!("{:*^12}", ByteBase2::from_dec(8)), "**00001000**".to_string());
+        assert_eq!(format!("{:#>12}", ByteBase2::from_dec(8)), "####00001000".to_string());
+        assert_eq!(format!("{:#12}", ByteBase2::from_dec(8)), "  0b00001000".to_string());
+        assert_eq!(format!("{:4}", ByteBase2::from_dec(8)), "00001000".to_string());
+        assert_eq!(format!("{:12}", ByteBase2::from_dec(8)), "    00001000".to_string());
+        assert_eq!(format!("{:.3}", ByteBase2::from_dec(15)), "00001111".to_string());
+        assert_eq!(format!("{:#.3}", ByteBase2::from_dec(15)), "0b00001111".to_string());
+        assert_eq!(format!("{:010}", ByteBase2::from_dec(15)), "0000001111".to_string());
+        assert_eq!(format!("{:#012}", ByteBase2::from_dec(15)), "0b0000001111".to_string());
+        assert_eq!(format!("{:+}", ByteBase2::from_dec(15)), "+00001111".to_string());
+        for byte in ByteBase2::all().step_by(9) {
+            assert_eq!(format!("{:>14}", byte), format!("{:>14b}", byte));
+            assert_eq!(format!("{:#014}", byte), format!("{:#014b}", byte));
+        }
+        assert_eq!(format!("{:>18}", BitsBase2::<16>::from_dec(1)), "  0000000000000001".to_string());
+    }
+
+    #[test]
+    fn bitand_test() {
+        let a = ByteBase2::from_dec(0b1100);
+        let b = ByteBase2::from_dec(0b1010);
+        let (ref_a, ref_b) = (&a, &b);
+        assert_eq!(ref_a & ref_b, ByteBase2::from_dec(0b1000));
+        assert_eq!(a & b, ByteBase2::from_dec(0b1000));
+        assert_eq!(a & ByteBase2::from_dec(255), a);
+        assert_eq!(a & ByteBase2::from_dec(0), ByteBase2::from_dec(0));
+    }
+
+    #[test]
+    fn bitor_test() {
+        let a = ByteBase2::from_dec(0b1100);
+        let b = ByteBase2::from_dec(0b0011);
+        let (ref_a, ref_b) = (&a, &b);
+        assert_eq!(ref_a | ref_b, ByteBase2::from_dec(0b1111));
+        assert_eq!(a | b, ByteBase2::from_dec(0b1111));
+        for value in [0, 1, 15, 128, 255].iter() {
+            let byte = ByteBase2::from_dec(*value);
+            assert_eq!(byte | ByteBase2::from_dec(0), byte);
+        }
+    }
+
+    #[test]
+    fn bitxor_test() {
+        let mask = ByteBase2::from_dec(0b1010_0101);
+        for value in [0, 1, 15, 128, 255].iter() {
+            let byte = ByteBase2::from_dec(*value);
+            assert_eq!((byte ^ mask) ^ mask, byte);
+            let (ref_byte, ref_mask) = (&byte, &mask);
+            assert_eq!(ref_byte ^ ref_mask, byte ^ mask);
+        }
+        assert_eq!(ByteBase2::from_dec(0xFF) ^ ByteBase2::from_dec(0xFF), ByteBase2::from_dec(0));
+    }
+
+    #[test]
+    fn not_test() {
+        assert_eq!(!ByteBase2::from_dec(0), ByteBase2::from_dec(255));
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!((!byte).as_dec(), !value);
+            assert_eq!(!!byte, byte);
+            assert_eq!(!&byte, !byte);
+        }
+    }
+
+    #[test]
+    fn assign_ops_test() {
+        let mut flags = ByteBase2::from_dec(0);
+        for mask in [1, 4, 32, 128].iter() {
+            flags |= ByteBase2::from_dec(*mask);
+        }
+        assert_eq!(flags.as_dec(), 165);
+        flags ^= ByteBase2::from_dec(4);
+        assert_eq!(flags.as_dec(), 161);
+        flags &= flags;
+        assert_eq!(flags.as_dec(), 161);
+    }
+
+    #[test]
+    fn shl_test() {
+        for value in 0..=255_u8 {
+            for shift in 0..8 {
+                assert_eq!((ByteBase2::from_dec(value) << shift as usize).as_dec(), value << shift);
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(1) << 3_u32, ByteBase2::from_dec(8));
+        assert_eq!(ByteBase2::from_dec(0xFF) << 8_usize, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(0xFF) << 100_u32, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(0xFF) << usize::MAX, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(1) << 3_u8, ByteBase2::from_dec(8));
+        assert_eq!(ByteBase2::from_dec(1) << 3_usize, ByteBase2::from_dec(8));
+        assert_eq!(ByteBase2::from_dec(0xFF) << 8_u8, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(0xFF) << u8::MAX, ByteBase2::from_dec(0));
+    }
+
+    #[test]
+    fn shr_test() {
+        for value in 0..=255_u8 {
+            for shift in 0..8 {
+                assert_eq!((ByteBase2::from_dec(value) >> shift as usize).as_dec(), value >> shift);
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(8) >> 3_u32, ByteBase2::from_dec(1));
+        assert_eq!(ByteBase2::from_dec(0xFF) >> 8_usize, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(0xFF) >> usize::MAX, ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(8) >> 3_u8, ByteBase2::from_dec(1));
+        assert_eq!(ByteBase2::from_dec(0xFF) >> 8_u8, ByteBase2::from_dec(0));
+        for shift in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(0b1011_0110);
+            assert_eq!(byte >> shift, byte >> shift as u32);
+            assert_eq!(byte << shift, byte << shift as usize);
+        }
+    }
+
+    #[test]
+    fn arithmetic_shift_right_test() {
+        for value in 0..=255_u8 {
+            for shift in 0..8 {
+                let expected = ((value as i8) >> shift) as u8;
+                assert_eq!(ByteBase2::from_dec(value).arithmetic_shift_right(shift).as_dec(), expected);
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(0b1010_0000).arithmetic_shift_right(3).as_dec(), 0b1111_0100);
+        assert_eq!(ByteBase2::from_dec(0b0111_1111).arithmetic_shift_right(8), ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(0b1000_0000).arithmetic_shift_right(8), ByteBase2::from_dec(255));
+        assert_eq!(ByteBase2::from_dec(0b1000_0000).arithmetic_shift_right(u32::MAX), ByteBase2::from_dec(255));
+    }
+
+    #[test]
+    fn rotate_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            for n in 0..20 {
+                assert_eq!(byte.rotate_left(n).as_dec(), value.rotate_left(n));
+                assert_eq!(byte.rotate_right(n).as_dec(), value.rotate_right(n));
+                assert_eq!(byte.rotate_left(n).rotate_right(n), byte);
+            }
+            assert_eq!(byte.rotate_left(8), byte);
+        }
+    }
+
+    #[test]
+    fn reverse_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.reverse().as_dec(), value.reverse_bits());
+            assert_eq!(byte.reverse().reverse(), byte);
+        }
+        let palindrome = ByteBase2::from_dec(0b10011001);
+        assert_eq!(palindrome.reverse(), palindrome);
+    }
+
+    #[test]
+    fn zeros_count_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.leading_zeros(), value.leading_zeros());
+            assert_eq!(byte.trailing_zeros(), value.trailing_zeros());
+        }
+        assert_eq!(ByteBase2::from_dec(0).leading_zeros(), 8);
+        assert_eq!(ByteBase2::from_dec(0).trailing_zeros(), 8);
+        assert_eq!(ByteBase2::from_dec(255).leading_zeros(), 0);
+        assert_eq!(ByteBase2::from_dec(255).trailing_zeros(), 0);
+    }
+
+    #[test]
+    fn ones_count_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.leading_ones(), value.leading_ones());
+            assert_eq!(byte.trailing_ones(), value.trailing_ones());
+        }
+        assert_eq!(ByteBase2::from_dec(255).leading_ones(), 8);
+        assert_eq!(ByteBase2::from_dec(255).trailing_ones(), 8);
+        assert_eq!(ByteBase2::from_dec(0).leading_ones(), 0);
+        assert_eq!(ByteBase2::from_dec(0).trailing_ones(), 0);
+    }
+
+    #[test]
+    fn zeros_test() {
+        for value in [0, 1, 15, 128, 170, 255].iter() {
+            let byte = ByteBase2::from_dec(*value);
+            assert_eq!(byte.ones() + byte.zeros(), 8);
+        }
+        assert_eq!(ByteBase2::from_dec(0).zeros(), 8);
+    }
+
+    #[test]
+    fn get_test() {
+        let mut byte = ByteBase2::from_dec(0b1010);
+        for index in 0..8 {
+            assert_eq!(byte.get(index), Some(byte[index]));
+        }
+        assert_eq!(byte.get(8), None);
+        assert_eq!(byte.get(usize::MAX), None);
+        *byte.get_mut(0).unwrap() = true;
+        assert_eq!(byte.as_dec(), 0b1011);
+        assert!(byte.get_mut(8).is_none());
+    }
+
+    #[test]
+    fn bit_helpers_test() {
+        let mut byte = ByteBase2::from_dec(0);
+        assert_eq!(byte.set_bit(8), Err(OutOfRange));
+        assert_eq!(byte.clear_bit(8), Err(OutOfRange));
+        assert_eq!(byte.toggle_bit(usize::MAX), Err(OutOfRange));
+        assert_eq!(byte.as_dec(), 0);
+        assert!(byte.set_bit(3).is_ok());
+        assert_eq!(byte.as_dec(), 8);
+        assert!(byte.set_bit(3).is_ok());
+        assert_eq!(byte.as_dec(), 8);
+        assert!(byte.toggle_bit(7).is_ok());
+        assert_eq!(byte.as_dec(), 136);
+        assert!(byte.clear_bit(3).is_ok());
+        assert_eq!(byte.as_dec(), 128);
+    }
+
+    #[test]
+    fn hex_test() {
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(ByteBase2::from_hex(&byte.as_hex()), Ok(byte));
+            assert_eq!(ByteBase2::from_hex(&byte.as_hex().to_uppercase()), Ok(byte));
+        }
+        assert_eq!(ByteBase2::from_hex("0X0f"), Ok(ByteBase2::from_dec(15)));
+        assert_eq!(ByteBase2::from_hex(""), Err(InvalidHex));
+        assert_eq!(ByteBase2::from_hex("0x"), Err(InvalidHex));
+        assert_eq!(ByteBase2::from_hex("+f"), Err(InvalidHex));
+        assert_eq!(ByteBase2::from_hex("é"), Err(InvalidHex));
+    }
+
+    #[test]
+    fn hex_format_test() {
+        assert_eq!(format!("{:x}", ByteBase2::from_dec(15)), "0f".to_string());
+        assert_eq!(format!("{:X}", ByteBase2::from_dec(15)), "0F".to_string());
+        assert_eq!(format!("{:#x}", ByteBase2::from_dec(171)), "0xab".to_string());
+        assert_eq!(format!("{:#X}", ByteBase2::from_dec(255)), "0xFF".to_string());
+        for value in 0..=255 {
+            assert_eq!(format!("{:x}", ByteBase2::from_dec(value)), ByteBase2::from_dec(value).as_hex());
+            assert_eq!(format!("{:x}", ByteBase2::from_dec(value)), format!("{:02x}", value));
+            assert_eq!(format!("{:#X}", ByteBase2::from_dec(value)), format!("{:#04X}", value));
+        }
+        let byte = ByteBase2::from_dec(8);
+        assert_eq!(format!("{:#06x}", byte), "0x0008".to_string());
+        assert_eq!(format!("{:06X}", byte), "000008".to_string());
+        assert_eq!(format!("{:>6x}", byte), "    08".to_string());
+        assert_eq!(format!("{:<6x}|", byte), "08    |".to_string());
+        assert_eq!(format!("{:*^#8X}", byte), "**0x08**".to_string());
+        assert_eq!(format!("{:1x}", byte), "08".to_string());
+    }
+
+    #[test]
+    fn binary_octal_format_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(format!("{:b}", byte), format!("{:08b}", value));
+            assert_eq!(format!("{:#b}", byte), format!("{:#010b}", value));
+            assert_eq!(format!("{:o}", byte), format!("{:o}", value));
+            assert_eq!(format!("{:#o}", byte), format!("{:#o}", value));
+        }
+        assert_eq!(format!("{:o}", ByteBase2::from_dec(8)), "10".to_string());
+        assert_eq!(format!("{:o}", ByteBase2::from_dec(255)), "377".to_string());
+        let byte = ByteBase2::from_dec(8);
+        assert_eq!(format!("{:6o}", byte), "    10".to_string());
+        assert_eq!(format!("{:<6o}|", byte), "10    |".to_string());
+        assert_eq!(format!("{:#08o}", byte), "0o000010".to_string());
+        assert_eq!(format!("{:>12b}", byte), "    00001000".to_string());
+        assert_eq!(format!("{:<12b}|", byte), "00001000    |".to_string());
+        assert_eq!(format!("{:#012b}", byte), "0b0000001000".to_string());
+        assert_eq!(format!("{:_^#14b}", byte), "__0b00001000__".to_string());
+        assert_eq!(format!("{:4b}", byte), "00001000".to_string());
+        for value in (0..=255_u8).step_by(5) {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(format!("{:>7o}", byte), format!("{:>7o}", value));
+            assert_eq!(format!("{:#07o}", byte), format!("{:#07o}", value));
+            assert_eq!(format!("{:#014b}", byte), format!("{:#014b}", value));
+        }
+    }
+
+    #[test]
+    fn from_u8_test() {
+        for value in 0..=255_u8 {
+            let byte: ByteBase2 = value.into();
+            assert_eq!(byte, ByteBase2::from_dec(value));
+            assert_eq!(u8::from(byte), value);
+            assert_eq!(u8::from(byte), byte.as_dec());
+        }
+    }
+
+    #[test]
+    fn parse_test() {
+        use std::convert::TryFrom;
+
+        assert_eq!("11110000".parse::<ByteBase2>().unwrap(), ByteBase2::from_dec(240));
+        assert_eq!("bad".parse::<ByteBase2>(), Err(InvalidPattern));
+        for pattern in ["00000000", "10101010", "11111111", "1111111", "0000000a"].iter() {
+            assert_eq!(ByteBase2::try_from(*pattern), ByteBase2::from_string(*pattern));
+            assert_eq!(pattern.parse::<ByteBase2>(), ByteBase2::from_string(*pattern));
+        }
+    }
+
+    #[test]
+    fn from_string_multibyte_test() {
+        assert_eq!("é000000".len(), 8);
+        assert_eq!(ByteBase2::from_string("é000000"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string("0000000é"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string("0000¹¹¹¹"), Err(InvalidPattern));
+    }
+
+    #[test]
+    fn from_str_ref_test() {
+        let patterns = ["00000000", "00001111", "11111111", "0000111", "000011111", "0000111a", "é000000", ""];
+        for pattern in patterns.iter() {
+            assert_eq!(ByteBase2::from_str_ref(pattern), ByteBase2::from_string(*pattern));
+        }
+    }
+
+    #[test]
+    fn error_test() {
+        let error: Box<dyn std::error::Error> = Box::new(ByteBase2::from_string("foo").unwrap_err());
+        assert_eq!(error.to_string(), format!("{:?}", InvalidPattern));
+        assert!(error.to_string().contains("'0' or '1'"));
+        let error: Box<dyn std::error::Error> = Box::new(ByteBase2::from_hex("fg").unwrap_err());
+        assert_eq!(error.to_string(), format!("{:?}", InvalidHex));
+        let error: Box<dyn std::error::Error> = Box::new(ByteBase2::from_dec(0).set_bit(8).unwrap_err());
+        assert_eq!(error.to_string(), format!("{:?}", OutOfRange));
+    }
+
+    #[test]
+    fn from_string_grouped_test() {
+        assert_eq!(ByteBase2::from_string_grouped("0000_1111"), Ok(ByteBase2::from_dec(15)));
+        assert_eq!(ByteBase2::from_string_grouped("00 00 11 11"), Ok(ByteBase2::from_dec(15)));
+        assert_eq!(ByteBase2::from_string_grouped("_1000 0001_"), Ok(ByteBase2::from_dec(129)));
+        assert_eq!(ByteBase2::from_string_grouped("10000001"), ByteBase2::from_string("10000001"));
+        assert_eq!(ByteBase2::from_string_grouped("0000__11"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string_grouped("0000_1111_1"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string_grouped("0000-1111"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string_grouped("0000_111x"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_string_grouped(""), Err(InvalidPattern));
+    }
+
+    #[test]
+    fn from_binary_str_test() {
+        assert_eq!(ByteBase2::from_binary_str("0b11110000").unwrap(), ByteBase2::from_dec(240));
+        assert_eq!(ByteBase2::from_binary_str("0B11110000").unwrap(), ByteBase2::from_dec(240));
+        assert_eq!(ByteBase2::from_binary_str("0b0000_1111"), Ok(ByteBase2::from_dec(15)));
+        assert_eq!(ByteBase2::from_binary_str("00001111"), ByteBase2::from_string("00001111"));
+        assert_eq!(ByteBase2::from_binary_str("0b"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_binary_str("0b12345678"), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_binary_str("0b0b00001111"), Err(InvalidPattern));
+    }
+
+    #[test]
+    fn bb_macro_test() {
+        assert_eq!(bb!(0000_1111), ByteBase2::from_dec(15));
+        assert_eq!(bb!(10000001), ByteBase2::from_dec(129));
+        assert_eq!(bb!("00001111"), ByteBase2::from_dec(15));
+        assert_eq!(bb!("1111_0000"), ByteBase2::from_dec(240));
+    }
+
+    #[test]
+    fn const_test() {
+        const MASK: ByteBase2 = ByteBase2::from_dec(0b1010_1010);
+        const MASK_VALUE: u8 = MASK.as_dec();
+        static FLAGS: ByteBase2 = ByteBase2::from_dec(15);
+        assert_eq!(MASK.as_dec(), 0b1010_1010);
+        assert_eq!(MASK_VALUE, 0b1010_1010);
+        assert_eq!(FLAGS.as_dec(), 15);
+    }
+
+    #[test]
+    fn array_test() {
+        assert_eq!(ByteBase2::from([true;8]).as_dec(), 255);
+        let bits = ByteBase2::from_dec(15).to_array();
+        assert!(bits[..4].iter().all(|bit| *bit));
+        assert!(bits[4..].iter().all(|bit| !*bit));
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(ByteBase2::from(byte.to_array()), byte);
+            for index in 0..8 {
+                assert_eq!(byte.to_array()[index], byte[index]);
+            }
+        }
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let byte = ByteBase2::from_dec(0b1001_0110);
+        let mut bits = Vec::new();
+        for bit in &byte {
+            bits.push(bit);
+        }
+        assert_eq!(bits, byte.iter().collect::<Vec<bool>>());
+        assert_eq!(byte.into_iter().collect::<Vec<bool>>(), bits);
+    }
+
+    #[test]
+    fn from_iter_test() {
+        let bits = [true, true, false, false, false, false, false, false];
+        assert_eq!(bits.iter().copied().collect::<ByteBase2>(), ByteBase2::from_dec(3));
+        assert_eq!(vec![true, false, true].into_iter().collect::<ByteBase2>(), ByteBase2::from_dec(5));
+        assert_eq!(Vec::new().into_iter().collect::<ByteBase2>(), ByteBase2::from_dec(0));
+        assert_eq!([true;12].iter().copied().collect::<ByteBase2>(), ByteBase2::from_dec(255));
+        let byte = ByteBase2::from_dec(0b1001_0110);
+        assert_eq!(byte.iter().collect::<ByteBase2>(), byte);
+    }
+
+    #[test]
+    fn iter_test() {
+        let byte = ByteBase2::from_dec(0b1001_0110);
+        let bits: Vec<bool> = byte.iter().collect();
+        assert_eq!(bits, vec![false, true, true, false, true, false, false, true]);
+        for (index, bit) in byte.iter().enumerate() {
+            assert_eq!(bit, byte[index]);
+        }
+        #[derive(Debug)]
+        struct Holder { _bits: crate::Iter }
+        let mut bits = byte.iter();
+        bits.next();
+        assert!(format!("{:?}", bits).starts_with("Iter"));
+        assert!(!format!("{:?}", Holder { _bits: bits }).is_empty());
+    }
+
+    #[test]
+    fn iter_double_ended_test() {
+        let byte = ByteBase2::from_dec(0b1001_0110);
+        let msb_first: Vec<bool> = format!("{:?}", byte).chars().map(|bit| bit == '1').collect();
+        assert_eq!(byte.iter().rev().collect::<Vec<bool>>(), msb_first);
+        assert_eq!(byte.iter().len(), 8);
+        let mut bits = byte.iter();
+        bits.next();
+        bits.next_back();
+        assert_eq!(bits.len(), 6);
+        assert_eq!(bits.collect::<Vec<bool>>().len(), 6);
+    }
+
+    #[test]
+    fn msb_first_iter_test() {
+        let bits: Vec<bool> = ByteBase2::from_string("10000000").unwrap().msb_first_iter().collect();
+        assert_eq!(bits, vec![true, false, false, false, false, false, false, false]);
+        let byte = ByteBase2::from_dec(0b1001_0110);
+        let written: String = byte.msb_first_iter().map(|bit| if bit { '1' } else { '0' }).collect();
+        assert_eq!(written, format!("{:?}", byte));
+    }
+
+    #[test]
+    fn hash_test() {
+        use std::collections::HashSet;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{ Hash, Hasher };
+
+        let hash = |byte: &ByteBase2| {
+            let mut hasher = DefaultHasher::new();
+            byte.hash(&mut hasher);
+            hasher.finish()
+        };
+        let bytes = [ByteBase2::from_dec(1), ByteBase2::from_dec(15), ByteBase2::from_dec(1), ByteBase2::from_dec(255)];
+        let set: HashSet<ByteBase2> = bytes.iter().copied().collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&ByteBase2::from_dec(15)));
+        assert_eq!(hash(&ByteBase2::from_dec(15)), hash(&ByteBase2::from_string("00001111").unwrap()));
+    }
+
+    #[test]
+    fn ord_test() {
+        let values = [200, 3, 128, 0, 255, 127, 1, 64];
+        let mut bytes: Vec<ByteBase2> = values.iter().map(|value| ByteBase2::from_dec(*value)).collect();
+        bytes.sort();
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        assert_eq!(bytes.iter().map(|byte| byte.as_dec()).collect::<Vec<u8>>(), sorted);
+        let set: std::collections::BTreeSet<ByteBase2> = bytes.iter().copied().collect();
+        assert_eq!(set.iter().next(), Some(&ByteBase2::from_dec(0)));
+        assert!(ByteBase2::from_dec(0x80) > ByteBase2::from_dec(0x7F));
+    }
+
+    #[test]
+    fn eq_u8_test() {
+        for value in 0..=255_u8 {
+            assert!(ByteBase2::from_dec(value) == value);
+            assert!(value == ByteBase2::from_dec(value));
+            assert!(ByteBase2::from_dec(value) != value.wrapping_add(1));
+            assert!(value.wrapping_add(1) != ByteBase2::from_dec(value));
+        }
+    }
+
+    #[test]
+    fn signed_test() {
+        assert_eq!(ByteBase2::from_i8(-1).as_dec(), 255);
+        assert_eq!(ByteBase2::from_dec(255).as_i8(), -1);
+        assert_eq!(ByteBase2::from_i8(-128).as_i8(), -128);
+        assert_eq!(ByteBase2::from_i8(127).as_i8(), 127);
+        for value in i8::MIN..=i8::MAX {
+            assert_eq!(ByteBase2::from_i8(value), ByteBase2::from_dec(value as u8));
+            assert_eq!(ByteBase2::from_i8(value).as_i8(), value);
+        }
+    }
+
+    #[test]
+    fn negate_test() {
+        for value in 0..=255_u8 {
+            assert_eq!(ByteBase2::from_dec(value).negate().as_dec(), value.wrapping_neg());
+        }
+        for value in -127..=127_i8 {
+            assert_eq!(ByteBase2::from_i8(value).negate().as_i8(), -value);
+        }
+        assert_eq!(ByteBase2::from_dec(0b1000_0000).negate(), ByteBase2::from_dec(0b1000_0000));
+    }
+
+    #[test]
+    fn wrapping_add_test() {
+        for a in 0..=255_u8 {
+            for b in [0, 1, 55, 128, 255].iter() {
+                let (a_byte, b_byte) = (ByteBase2::from_dec(a), ByteBase2::from_dec(*b));
+                assert_eq!(a_byte.wrapping_add(b_byte).as_dec(), a.wrapping_add(*b));
+                let (output, carry) = a.overflowing_add(*b);
+                assert_eq!(a_byte.overflowing_add(b_byte), (ByteBase2::from_dec(output), carry));
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(255).wrapping_add(ByteBase2::from_dec(1)), ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(255).overflowing_add(ByteBase2::from_dec(1)), (ByteBase2::from_dec(0), true));
+        assert_eq!(ByteBase2::from_dec(254).overflowing_add(ByteBase2::from_dec(1)), (ByteBase2::from_dec(255), false));
+    }
+
+    #[test]
+    fn checked_saturating_add_test() {
+        for a in 0..=255_u8 {
+            for b in [0, 1, 55, 128, 255].iter() {
+                let (a_byte, b_byte) = (ByteBase2::from_dec(a), ByteBase2::from_dec(*b));
+                assert_eq!(a_byte.checked_add(b_byte), a.checked_add(*b).map(ByteBase2::from_dec));
+                assert_eq!(a_byte.saturating_add(b_byte).as_dec(), a.saturating_add(*b));
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(200).checked_add(ByteBase2::from_dec(100)), None);
+        assert_eq!(ByteBase2::from_dec(200).saturating_add(ByteBase2::from_dec(100)), ByteBase2::from_dec(255));
+        assert_eq!(ByteBase2::from_dec(200).checked_add(ByteBase2::from_dec(55)), Some(ByteBase2::from_dec(255)));
+    }
+
+    #[test]
+    fn wrapping_sub_test() {
+        for a in 0..=255_u8 {
+            for b in [0, 1, 55, 128, 255].iter() {
+                let (a_byte, b_byte) = (ByteBase2::from_dec(a), ByteBase2::from_dec(*b));
+                assert_eq!(a_byte.wrapping_sub(b_byte).as_dec(), a.wrapping_sub(*b));
+                assert_eq!(a_byte.wrapping_sub(b_byte).wrapping_add(b_byte), a_byte);
+                let (output, borrow) = a.overflowing_sub(*b);
+                assert_eq!(a_byte.overflowing_sub(b_byte), (ByteBase2::from_dec(output), borrow));
+            }
+        }
+        assert_eq!(ByteBase2::from_dec(0).overflowing_sub(ByteBase2::from_dec(1)), (ByteBase2::from_dec(255), true));
+        assert_eq!(ByteBase2::from_dec(1).overflowing_sub(ByteBase2::from_dec(1)), (ByteBase2::from_dec(0), false));
+    }
+
+    #[test]
+    fn nibble_test() {
+        assert_eq!(ByteBase2::from_dec(0xF0).high_nibble(), 15);
+        assert_eq!(ByteBase2::from_dec(0xF0).low_nibble(), 0);
+        assert_eq!(ByteBase2::from_dec(0x0F).high_nibble(), 0);
+        assert_eq!(ByteBase2::from_dec(0x0F).low_nibble(), 15);
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.high_nibble() * 16 + byte.low_nibble(), value);
+        }
+    }
+
+    #[test]
+    fn swap_nibbles_test() {
+        assert_eq!(ByteBase2::from_dec(0x0F).swap_nibbles(), ByteBase2::from_dec(0xF0));
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.swap_nibbles().as_dec(), value.rotate_left(4));
+            assert_eq!(byte.swap_nibbles().swap_nibbles(), byte);
+        }
+    }
+
+    #[test]
+    fn hamming_distance_test() {
+        let (zero, full) = (ByteBase2::from_dec(0), ByteBase2::from_dec(255));
+        assert_eq!(zero.hamming_distance(&full), 8);
+        assert_eq!(full.hamming_distance(&zero), 8);
+        assert_eq!(ByteBase2::from_dec(0b1010).hamming_distance(&ByteBase2::from_dec(0b0101)), 4);
+        assert_eq!(ByteBase2::from_dec(0b1000_0001).hamming_distance(&ByteBase2::from_dec(0b1000_0000)), 1);
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.hamming_distance(&byte), 0);
+            assert_eq!(byte.hamming_distance(&zero), value.count_ones());
+        }
+    }
+
+    #[test]
+    fn parity_test() {
+        assert!(ByteBase2::from_dec(0b111).parity());
+        assert!(!ByteBase2::from_dec(0b11).parity());
+        assert!(!ByteBase2::from_dec(0).parity());
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.parity(), value.count_ones() % 2 == 1);
+            assert_eq!((byte.ones() + byte.even_parity_bit() as usize) % 2, 0);
+        }
+    }
+
+    #[test]
+    fn predicates_test() {
+        assert!(ByteBase2::from_dec(8).is_power_of_two());
+        assert!(!ByteBase2::from_dec(6).is_power_of_two());
+        assert!(!ByteBase2::from_dec(0).is_power_of_two());
+        assert!(ByteBase2::from_dec(0).is_zero());
+        assert!(ByteBase2::from_dec(255).is_all_ones());
+        for value in 1..=254_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.is_power_of_two(), value.is_power_of_two());
+            assert!(!byte.is_zero());
+            assert!(!byte.is_all_ones());
+        }
+    }
+
+    #[test]
+    fn gray_test() {
+        let known = [(0, 0), (1, 1), (2, 3), (3, 2), (4, 6), (7, 4), (8, 12), (255, 128)];
+        for (binary, gray) in known.iter() {
+            assert_eq!(ByteBase2::from_dec(*binary).to_gray(), ByteBase2::from_dec(*gray));
+            assert_eq!(ByteBase2::from_dec(*gray).from_gray(), ByteBase2::from_dec(*binary));
+        }
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.to_gray().as_dec(), value ^ (value >> 1));
+            assert_eq!(byte.to_gray().from_gray(), byte);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_test() {
+        let byte = ByteBase2::from_dec(15);
+        assert_eq!(serde_json::to_string(&byte).unwrap(), "\"00001111\"".to_string());
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            let json = serde_json::to_string(&byte).unwrap();
+            assert_eq!(serde_json::from_str::<ByteBase2>(&json).unwrap(), byte);
+        }
+        assert!(serde_json::from_str::<ByteBase2>("\"0000111\"").is_err());
+        assert!(serde_json::from_str::<ByteBase2>("\"0000111a\"").is_err());
+        assert!(serde_json::from_str::<ByteBase2>("15").is_err());
+    }
+
+    #[test]
+    fn checked_shift_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            for n in 0..8_u32 {
+                assert_eq!(byte.checked_shl(n), Some(byte << n));
+                assert_eq!(byte.checked_shr(n), Some(byte >> n));
+                assert_eq!(byte.checked_shl(n).map(|byte| byte.as_dec()), value.checked_shl(n));
+                assert_eq!(byte.checked_shr(n).map(|byte| byte.as_dec()), value.checked_shr(n));
+            }
+            assert_eq!(byte.checked_shl(8), None);
+            assert_eq!(byte.checked_shr(8), None);
+            assert_eq!(byte.checked_shl(u32::MAX), None);
+        }
+    }
+
+    #[test]
+    fn u16_test() {
+        assert_eq!(ByteBase2::to_u16(&ByteBase2::from_dec(0x12), &ByteBase2::from_dec(0x34)), 0x1234);
+        assert_eq!(ByteBase2::from_u16(0xABCD), [ByteBase2::from_dec(0xAB), ByteBase2::from_dec(0xCD)]);
+        for word in [0, 1, 0xFF, 0x100, 0x1234, 0xFFFF].iter() {
+            let [high, low] = ByteBase2::from_u16(*word);
+            assert_eq!(ByteBase2::to_u16(&high, &low), *word);
+            assert_eq!([high.as_dec(), low.as_dec()], word.to_be_bytes());
+        }
+    }
+
+    #[test]
+    fn extend_test() {
+        assert_eq!(ByteBase2::from_dec(0x80).sign_extend_to_i16(), -128);
+        assert_eq!(ByteBase2::from_dec(0x80).zero_extend_to_u16(), 128);
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.sign_extend_to_i16(), value as i8 as i16);
+            assert_eq!(byte.zero_extend_to_u16(), value as u16);
+        }
+    }
+
+    #[test]
+    fn increment_decrement_test() {
+        let mut byte = ByteBase2::from_dec(255);
+        byte.increment();
+        assert_eq!(byte, ByteBase2::from_dec(0));
+        byte.decrement();
+        assert_eq!(byte, ByteBase2::from_dec(255));
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.succ().pred(), byte);
+            assert_eq!(byte.pred().succ(), byte);
+            assert_eq!(byte.succ().as_dec(), value.wrapping_add(1));
+            let mut copy = byte;
+            copy.increment();
+            assert_eq!(copy, byte.succ());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "step_trait")]
+    fn step_test() {
+        let bytes: Vec<ByteBase2> = (ByteBase2::from_dec(0)..=ByteBase2::from_dec(255)).collect();
+        assert_eq!(bytes.len(), 256);
+        for (value, byte) in bytes.iter().enumerate() {
+            assert_eq!(byte.as_dec() as usize, value);
+        }
+        assert_eq!((ByteBase2::from_dec(10)..ByteBase2::from_dec(5)).count(), 0);
+    }
+
+    #[test]
+    fn all_test() {
+        let bytes: Vec<ByteBase2> = ByteBase2::all().collect();
+        assert_eq!(bytes.len(), 256);
+        assert_eq!(bytes.first(), Some(&ByteBase2::from_dec(0)));
+        assert_eq!(bytes.last(), Some(&ByteBase2::from_dec(255)));
+        assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn bits_test() {
+        let byte = ByteBase2::from_dec(0xF0);
+        assert_eq!(byte.bits(0..4), 0);
+        assert_eq!(byte.bits(4..8), 15);
+        assert_eq!(byte.bits(3..3), 0);
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.bits(0..8), value);
+            assert_eq!(byte.bits(0..4), byte.low_nibble());
+            assert_eq!(byte.bits(4..8), byte.high_nibble());
+            assert_eq!(byte.bits(2..5), (value >> 2) & 0b111);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bits_out_of_range_test() {
+        ByteBase2::from_dec(0).bits(4..9);
+    }
+
+    #[test]
+    fn to_bool_vec_test() {
+        assert_eq!(ByteBase2::from_dec(3).to_bool_vec(), vec![true, true, false, false, false, false, false, false]);
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.to_bool_vec(), byte.iter().collect::<Vec<bool>>());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn rand_test() {
+        use rand::{ Rng, SeedableRng, rngs::StdRng };
+        use std::collections::HashSet;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let bytes: Vec<ByteBase2> = (0..4096).map(|_| rng.gen()).collect();
+        let mut rng = StdRng::seed_from_u64(0);
+        let values: Vec<u8> = (0..4096).map(|_| rng.gen()).collect();
+        assert_eq!(bytes, values.iter().map(|value| ByteBase2::from_dec(*value)).collect::<Vec<ByteBase2>>());
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!((0..4096).map(|_| rng.gen()).collect::<Vec<ByteBase2>>(), bytes);
+        assert_eq!(bytes.iter().collect::<HashSet<_>>().len(), 256);
+    }
+
+    #[test]
+    fn rotate_through_carry_test() {
+        let (byte, carry) = ByteBase2::from_dec(0b1100_0000).rotate_left_through_carry(false);
+        assert_eq!((byte, carry), (ByteBase2::from_dec(0b1000_0000), true));
+        let (byte, carry) = byte.rotate_left_through_carry(carry);
+        assert_eq!((byte, carry), (ByteBase2::from_dec(0b0000_0001), true));
+        let (byte, carry) = ByteBase2::from_dec(0b0000_0011).rotate_right_through_carry(true);
+        assert_eq!((byte, carry), (ByteBase2::from_dec(0b1000_0001), true));
+        let (byte, carry) = byte.rotate_right_through_carry(carry);
+        assert_eq!((byte, carry), (ByteBase2::from_dec(0b1100_0000), true));
+        for value in 0..=255 {
+            for carry_in in [false, true].iter() {
+                let (mut byte, mut carry) = (ByteBase2::from_dec(value), *carry_in);
+                for _ in 0..9 {
+                    let rotated = byte.rotate_left_through_carry(carry);
+                    byte = rotated.0;
+                    carry = rotated.1;
+                }
+                assert_eq!((byte, carry), (ByteBase2::from_dec(value), *carry_in));
+                let rotated = ByteBase2::from_dec(value).rotate_left_through_carry(*carry_in);
+                assert_eq!(rotated.0.rotate_right_through_carry(rotated.1), (ByteBase2::from_dec(value), *carry_in));
+            }
+        }
+    }
+
+    #[test]
+    fn reverse_nibbles_test() {
+        assert_eq!(ByteBase2::from_dec(0b0001_1000).reverse_nibbles(), ByteBase2::from_dec(0b1000_0001));
+        assert_eq!(ByteBase2::from_dec(0b0011_0010).reverse_nibbles(), ByteBase2::from_dec(0b1100_0100));
+        assert_eq!(ByteBase2::from_dec(0b1110_0000).reverse_nibbles(), ByteBase2::from_dec(0b0111_0000));
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.reverse_nibbles().reverse_nibbles(), byte);
+            assert_eq!(byte.reverse_nibbles(), byte.reverse().swap_nibbles());
+        }
+    }
+
+    #[test]
+    fn swap_bits_test() {
+        let mut byte = ByteBase2::from_dec(1);
+        assert!(byte.swap_bits(0, 7).is_ok());
+        assert_eq!(byte, ByteBase2::from_dec(128));
+        assert!(byte.swap_bits(3, 3).is_ok());
+        assert_eq!(byte, ByteBase2::from_dec(128));
+        assert!(byte.swap_bits(7, 1).is_ok());
+        assert_eq!(byte, ByteBase2::from_dec(2));
+        assert_eq!(byte.swap_bits(0, 8), Err(OutOfRange));
+        assert_eq!(byte.swap_bits(8, 1), Err(OutOfRange));
+        assert_eq!(byte, ByteBase2::from_dec(2));
+    }
+
+    #[test]
+    fn is_palindrome_test() {
+        assert!(ByteBase2::from_dec(0b1001_1001).is_palindrome());
+        assert!(ByteBase2::from_dec(0b0110_0110).is_palindrome());
+        assert!(!ByteBase2::from_dec(0b0000_0001).is_palindrome());
+        assert!(!ByteBase2::from_dec(0b1001_1000).is_palindrome());
+        assert!(ByteBase2::from_dec(0).is_palindrome());
+        assert!(ByteBase2::from_dec(255).is_palindrome());
+        assert_eq!(ByteBase2::all().filter(|byte| byte.is_palindrome()).count(), 16);
+    }
+
+    #[test]
+    fn bit_from_msb_test() {
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            let written = format!("{:?}", byte);
+            assert_eq!(byte.bit_from_msb(0), Some(written.starts_with('1')));
+            for (n, digit) in written.chars().enumerate() {
+                assert_eq!(byte.bit_from_msb(n), Some(digit == '1'));
+                assert_eq!(byte.bit_from_msb(n), byte.get(7 - n));
+            }
+            assert_eq!(byte.bit_from_msb(8), None);
+        }
+    }
+
+    #[test]
+    fn bits_set_test() {
+        assert_eq!(ByteBase2::from_dec(0).bits_set(), Vec::<usize>::new());
+        assert_eq!(ByteBase2::from_dec(255).bits_set(), (0..8).collect::<Vec<usize>>());
+        assert_eq!(ByteBase2::from_dec(0b1001).bits_set(), vec![0, 3]);
+        assert_eq!(ByteBase2::from_dec(0b1010_0100).bits_set(), vec![2, 5, 7]);
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.set_bit_indices().count(), byte.ones());
+            assert!(byte.set_bit_indices().all(|idx| byte[idx]));
+        }
+    }
+
+    #[test]
+    fn find_set_test() {
+        assert_eq!(ByteBase2::from_dec(0).find_first_set(), None);
+        assert_eq!(ByteBase2::from_dec(0).find_last_set(), None);
+        assert_eq!(ByteBase2::from_dec(0b00010100).find_first_set(), Some(2));
+        assert_eq!(ByteBase2::from_dec(0b00010100).find_last_set(), Some(4));
+        assert_eq!(ByteBase2::from_dec(255).find_first_set(), Some(0));
+        assert_eq!(ByteBase2::from_dec(255).find_last_set(), Some(7));
+        for idx in 0..8 {
+            let byte = ByteBase2::from_dec(1 << idx);
+            assert_eq!(byte.find_first_set(), Some(idx));
+            assert_eq!(byte.find_last_set(), Some(idx));
+        }
+        for value in 1..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.find_first_set(), Some(byte.trailing_zeros() as usize));
+            assert_eq!(byte.find_last_set(), Some(7 - byte.leading_zeros() as usize));
+        }
+    }
+
+    #[test]
+    fn ascii_char_test() {
+        assert_eq!(ByteBase2::from_dec(65).as_ascii_char(), Some('A'));
+        assert_eq!(ByteBase2::from_dec(0).as_ascii_char(), Some('\0'));
+        assert_eq!(ByteBase2::from_dec(127).as_ascii_char(), Some('\x7f'));
+        assert_eq!(ByteBase2::from_dec(128).as_ascii_char(), None);
+        assert_eq!(ByteBase2::from_ascii_char('A').unwrap(), ByteBase2::from_dec(65));
+        assert_eq!(ByteBase2::from_ascii_char('\u{ff}').unwrap(), ByteBase2::from_dec(255));
+        assert_eq!(ByteBase2::from_ascii_char('\u{100}'), Err(InvalidPattern));
+        for value in 0..=127 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(ByteBase2::from_ascii_char(byte.as_ascii_char().unwrap()).unwrap(), byte);
+        }
+    }
+
+    #[test]
+    fn mul_test() {
+        assert_eq!(ByteBase2::from_dec(16).full_mul(ByteBase2::from_dec(16)), 256);
+        assert_eq!(ByteBase2::from_dec(200).wrapping_mul(ByteBase2::from_dec(2)), ByteBase2::from_dec(144));
+        for a in (0..=255).step_by(7) {
+            for b in (0..=255).step_by(11) {
+                let (byte_a, byte_b) = (ByteBase2::from_dec(a), ByteBase2::from_dec(b));
+                assert_eq!(byte_a.full_mul(byte_b), a as u16 * b as u16);
+                assert_eq!(byte_a.wrapping_mul(byte_b).as_dec(), (a as u16 * b as u16) as u8);
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_test() {
+        let zero = ByteBase2::from_dec(0);
+        assert_eq!(ByteBase2::from_dec(10).checked_div(zero), None);
+        assert_eq!(ByteBase2::from_dec(10).checked_rem(zero), None);
+        assert_eq!(ByteBase2::from_dec(10).checked_div(ByteBase2::from_dec(3)), Some(ByteBase2::from_dec(3)));
+        assert_eq!(ByteBase2::from_dec(10).checked_rem(ByteBase2::from_dec(3)), Some(ByteBase2::from_dec(1)));
+        for a in 0..=255 {
+            for b in (1..=255).step_by(13) {
+                let (byte_a, byte_b) = (ByteBase2::from_dec(a), ByteBase2::from_dec(b));
+                let quotient = byte_a.checked_div(byte_b).unwrap();
+                let remainder = byte_a.checked_rem(byte_b).unwrap();
+                assert_eq!(quotient.full_mul(byte_b) + remainder.as_dec() as u16, a as u16);
+            }
+        }
+    }
+
+    #[test]
+    fn to_grouped_string_test() {
+        let byte = ByteBase2::from_dec(0b0000_1111);
+        assert_eq!(byte.to_grouped_string(4, '_'), "0000_1111");
+        assert_eq!(byte.to_grouped_string(2, ' '), "00 00 11 11");
+        assert_eq!(byte.to_grouped_string(3, '_'), "00_001_111");
+        assert_eq!(byte.to_grouped_string(1, '.'), "0.0.0.0.1.1.1.1");
+        assert_eq!(byte.to_grouped_string(0, '_'), "00001111");
+        assert_eq!(byte.to_grouped_string(8, '_'), "00001111");
+        assert_eq!(byte.to_grouped_string(12, '_'), "00001111");
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.to_grouped_string(4, '_').replace('_', ""), format!("{:?}", byte));
+        }
+    }
+
+    #[test]
+    fn to_compact_string_test() {
+        assert_eq!(ByteBase2::from_dec(5).to_compact_string(), "101");
+        assert_eq!(ByteBase2::from_dec(0).to_compact_string(), "0");
+        assert_eq!(ByteBase2::from_dec(1).to_compact_string(), "1");
+        assert_eq!(ByteBase2::from_dec(255).to_compact_string(), "11111111");
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            let compact = byte.to_compact_string();
+            assert_eq!(compact.len(), 8 - (byte.leading_zeros() as usize).min(7));
+            assert_eq!(u8::from_str_radix(&compact, 2).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn add_test() {
+        assert_eq!(ByteBase2::from_dec(1) + ByteBase2::from_dec(2), ByteBase2::from_dec(3));
+        assert_eq!(ByteBase2::from_dec(255) + ByteBase2::from_dec(1), ByteBase2::from_dec(0));
+        assert_eq!(ByteBase2::from_dec(200) + ByteBase2::from_dec(100), ByteBase2::from_dec(44));
+        let (ref_a, ref_b) = (&ByteBase2::from_dec(20), &ByteBase2::from_dec(22));
+        assert_eq!(ref_a + ref_b, ByteBase2::from_dec(42));
+        for a in (0..=255).step_by(3) {
+            for b in (0..=255).step_by(5) {
+                let (byte_a, byte_b) = (ByteBase2::from_dec(a), ByteBase2::from_dec(b));
+                assert_eq!(byte_a + byte_b, byte_a.wrapping_add(byte_b));
+            }
+        }
+    }
+
+    #[test]
+    fn sub_test() {
+        assert_eq!(ByteBase2::from_dec(5) - ByteBase2::from_dec(3), ByteBase2::from_dec(2));
+        assert_eq!(ByteBase2::from_dec(0) - ByteBase2::from_dec(1), ByteBase2::from_dec(255));
+        assert_eq!(ByteBase2::from_dec(100) - ByteBase2::from_dec(200), ByteBase2::from_dec(156));
+        let (ref_a, ref_b) = (&ByteBase2::from_dec(50), &ByteBase2::from_dec(8));
+        assert_eq!(ref_a - ref_b, ByteBase2::from_dec(42));
+        for a in (0..=255).step_by(3) {
+            for b in (0..=255).step_by(5) {
+                let (byte_a, byte_b) = (ByteBase2::from_dec(a), ByteBase2::from_dec(b));
+                assert_eq!(byte_a - byte_b, byte_a.wrapping_sub(byte_b));
+                assert_eq!(byte_a - byte_b + byte_b, byte_a);
+            }
+        }
+    }
+
+    #[test]
+    fn full_adder_test() {
+        assert_eq!(ByteBase2::from_dec(255).full_adder(ByteBase2::from_dec(0), true), (ByteBase2::from_dec(0), true));
+        assert_eq!(ByteBase2::from_dec(255).full_adder(ByteBase2::from_dec(255), true), (ByteBase2::from_dec(255), true));
+        assert_eq!(ByteBase2::from_dec(0).full_adder(ByteBase2::from_dec(0), false), (ByteBase2::from_dec(0), false));
+        for (a, b) in [(0x12FF_u16, 0x0001_u16), (0xABCD, 0x1234), (0xFFFF, 0x0001), (0x8000, 0x8000), (0x00FF, 0x00FF)].iter() {
+            let [a_high, a_low] = ByteBase2::from_u16(*a);
+            let [b_high, b_low] = ByteBase2::from_u16(*b);
+            let (low, carry) = a_low.full_adder(b_low, false);
+            let (high, carry) = a_high.full_adder(b_high, carry);
+            let (expected, overflow) = a.overflowing_add(*b);
+            assert_eq!(ByteBase2::to_u16(&high, &low), expected);
+            assert_eq!(carry, overflow);
+        }
+    }
+
+    #[test]
+    fn bitwise_u8_test() {
+        assert_eq!(ByteBase2::from_dec(0xFF) & 0x0F_u8, ByteBase2::from_dec(0x0F));
+        assert_eq!(ByteBase2::from_dec(0x0F) | 0xF0_u8, ByteBase2::from_dec(0xFF));
+        assert_eq!(ByteBase2::from_dec(0x0F) ^ 0xFF_u8, ByteBase2::from_dec(0xF0));
+        for a in 0..=255 {
+            for b in (0..=255).step_by(17) {
+                let byte = ByteBase2::from_dec(a);
+                assert_eq!(byte & b, byte & ByteBase2::from_dec(b));
+                assert_eq!(byte | b, byte | ByteBase2::from_dec(b));
+                assert_eq!(byte ^ b, byte ^ ByteBase2::from_dec(b));
+            }
+        }
+    }
+
+    #[test]
+    fn min_max_clamp_test() {
+        let (low, high) = (ByteBase2::from_dec(3), ByteBase2::from_dec(10));
+        assert_eq!(ByteBase2::from_dec(5).min(ByteBase2::from_dec(9)), ByteBase2::from_dec(5));
+        assert_eq!(ByteBase2::from_dec(5).max(ByteBase2::from_dec(9)), ByteBase2::from_dec(9));
+        assert_eq!(ByteBase2::from_dec(15).clamp(low, high), high);
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.min(high).as_dec(), value.min(10));
+            assert_eq!(byte.max(low).as_dec(), value.max(3));
+            assert_eq!(byte.clamp(low, high).as_dec(), value.clamp(3, 10));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_invalid_interval_test() {
+        ByteBase2::from_dec(5).clamp(ByteBase2::from_dec(10), ByteBase2::from_dec(3));
+    }
+
+    #[test]
+    fn mask_query_test() {
+        let flags = ByteBase2::from_dec(0b1110);
+        assert!(flags.contains(&ByteBase2::from_dec(0b0110)));
+        assert!(flags.contains(&ByteBase2::from_dec(0)));
+        assert!(flags.contains(&flags));
+        assert!(!flags.contains(&ByteBase2::from_dec(0b0001)));
+        assert!(flags.intersects(&ByteBase2::from_dec(0b0110)));
+        assert!(!flags.intersects(&ByteBase2::from_dec(0b0001)));
+        assert!(!flags.intersects(&ByteBase2::from_dec(0)));
+        assert_eq!(flags.difference(ByteBase2::from_dec(0b0110)), ByteBase2::from_dec(0b1000));
+        assert_eq!(flags.difference(ByteBase2::from_dec(0)), flags);
+        assert_eq!(flags.difference(flags), ByteBase2::from_dec(0));
+        for a in 0..=255_u8 {
+            for b in (0..=255_u8).step_by(7) {
+                let (byte_a, byte_b) = (ByteBase2::from_dec(a), ByteBase2::from_dec(b));
+                assert_eq!(byte_a.contains(&byte_b), a & b == b);
+                assert_eq!(byte_a.intersects(&byte_b), a & b != 0);
+                assert_eq!(byte_a.difference(byte_b).as_dec(), a & !b);
+            }
+        }
+    }
+
+    #[test]
+    fn set_clear_toggle_all_test() {
+        for value in 0..=255 {
+            let mut byte = ByteBase2::from_dec(value);
+            byte.set_all();
+            assert_eq!(byte.as_dec(), 255);
+            byte.clear_all();
+            assert_eq!(byte.as_dec(), 0);
+            let mut byte = ByteBase2::from_dec(value);
+            byte.toggle_all();
+            assert_eq!(byte, !ByteBase2::from_dec(value));
+            byte.toggle_all();
+            assert_eq!(byte.as_dec(), value);
+        }
+    }
+
+    #[test]
+    fn from_set_indices_test() {
+        assert_eq!(ByteBase2::from_set_indices(Vec::new()), Ok(ByteBase2::from_dec(0)));
+        assert_eq!(ByteBase2::from_set_indices(vec![0, 3]), Ok(ByteBase2::from_dec(0b1001)));
+        assert_eq!(ByteBase2::from_set_indices(vec![3, 0, 3, 3]), Ok(ByteBase2::from_dec(0b1001)));
+        assert_eq!(ByteBase2::from_set_indices(0..8), Ok(ByteBase2::from_dec(255)));
+        assert_eq!(ByteBase2::from_set_indices(vec![1, 8]), Err(OutOfRange));
+        assert_eq!(ByteBase2::from_set_indices(vec![usize::MAX]), Err(OutOfRange));
+        for value in 0..=255 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(ByteBase2::from_set_indices(byte.set_bit_indices()), Ok(byte));
+        }
+    }
+
+    #[test]
+    fn count_ones_zeros_test() {
+        for value in 0..=255_u8 {
+            let byte = ByteBase2::from_dec(value);
+            assert_eq!(byte.count_ones(), byte.ones() as u32);
+            assert_eq!(byte.count_zeros(), byte.zeros() as u32);
+            assert_eq!(byte.count_ones(), value.count_ones());
+            assert_eq!(byte.count_ones() + byte.count_zeros(), 8);
+        }
+    }
+
+    #[test]
+    fn xor_checksum_test() {
+        assert_eq!(xor_checksum(&[]), ByteBase2::from_dec(0));
+        assert_eq!(xor_checksum(&[ByteBase2::from_dec(0x5A)]), ByteBase2::from_dec(0x5A));
+        assert_eq!(xor_checksum(&[ByteBase2::from_dec(0x0F), ByteBase2::from_dec(0xF0)]), ByteBase2::from_dec(0xFF));
+        let mut data: Vec<ByteBase2> = [0x12, 0x34, 0x56, 0x78, 0x9A].iter().map(|value| ByteBase2::from_dec(*value)).collect();
+        let checksum = xor_checksum(&data);
+        assert_eq!(checksum.as_dec(), 0x12 ^ 0x34 ^ 0x56 ^ 0x78 ^ 0x9A);
+        data.push(checksum);
+        assert_eq!(xor_checksum(&data), ByteBase2::from_dec(0));
+    }
+
+    #[test]
+    fn total_ones_test() {
+        assert_eq!(total_ones(&[]), 0);
+        assert_eq!(total_ones(&[ByteBase2::from_dec(0xFF), ByteBase2::from_dec(0x0F)]), 12);
+        let all: Vec<ByteBase2> = ByteBase2::all().collect();
+        assert_eq!(total_ones(&all), 256 * 4);
+        assert_eq!(bit_density(&[]), 0.0);
+        assert_eq!(bit_density(&[ByteBase2::from_dec(0xFF); 3]), 1.0);
+        assert_eq!(bit_density(&[ByteBase2::from_dec(0); 3]), 0.0);
+        assert_eq!(bit_density(&[ByteBase2::from_dec(0x01), ByteBase2::from_dec(0x07)]), 0.25);
+        assert_eq!(bit_density(&all), 0.5);
+    }
+
+    #[test]
+    fn describe_flags_test() {
+        let names = ["CF", "ZF", "IF", "DF", "OF", "SF", "TF", "AF"];
+        assert_eq!(ByteBase2::from_dec(0).describe_flags(&names), Vec::<&str>::new());
+        assert_eq!(ByteBase2::from_dec(0b101).describe_flags(&names), vec!["CF", "IF"]);
+        assert_eq!(ByteBase2::from_dec(0b1000_0010).describe_flags(&names), vec!["ZF", "AF"]);
+        assert_eq!(ByteBase2::from_dec(255).describe_flags(&names), names.to_vec());
+    }
+
+    #[test]
+    fn write_to_test() {
+        let values = [0, 1, 15, 128, 240, 255];
+        let mut buffer = Vec::new();
+        for value in values.iter() {
+            ByteBase2::from_dec(*value).write_to(&mut buffer).unwrap();
+        }
+        assert_eq!(buffer, values.to_vec());
+        let mut full = [0_u8; 0];
+        assert!(ByteBase2::from_dec(1).write_to(&mut &mut full[..]).is_err());
+    }
+
+    #[test]
+    fn read_from_test() {
+        let mut buffer = Vec::new();
+        for byte in ByteBase2::all() {
+            byte.write_to(&mut buffer).unwrap();
+        }
+        let mut reader = &buffer[..];
+        for byte in ByteBase2::all() {
+            assert_eq!(ByteBase2::read_from(&mut reader).unwrap(), byte);
+        }
+        let error = ByteBase2::read_from(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        let mut empty = std::io::empty();
+        assert_eq!(ByteBase2::read_from(&mut empty).unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn into_string_test() {
+        assert_eq!(String::from(ByteBase2::from_dec(15)), "00001111");
+        assert_eq!(String::from(&ByteBase2::from_dec(240)), "11110000");
+        for byte in ByteBase2::all() {
+            let pattern: String = byte.into();
+            assert_eq!(pattern, format!("{:?}", byte));
+            assert_eq!(ByteBase2::from_string(pattern), Ok(byte));
+        }
+    }
+
+    #[test]
+    fn bits_base2_test() {
+        let word = BitsBase2::<16>::from_dec(0b1000_0000_0000_0111);
+        assert_eq!(word.ones(), 4);
+        assert_eq!(word.zeros(), 12);
+        assert_eq!(format!("{:?}", word), "1000000000000111");
+        assert_eq!(format!("{:#}", word), "0b1000000000000111");
+        assert_eq!(word.iter().len(), 16);
+        assert_eq!(word.iter().filter(|bit| *bit).count(), 4);
+        assert!(word[0] && word[15] && !word[8]);
+        assert_eq!(word.get(16), None);
+        let mut word = word;
+        word[8] = true;
+        *word.get_mut(9).unwrap() = true;
+        assert_eq!(word.as_dec(), 0b1000_0011_0000_0111);
+        for value in (0..=u16::MAX).step_by(97) {
+            assert_eq!(BitsBase2::<16>::from_dec(value).as_dec(), value);
+            assert_eq!(format!("{:?}", BitsBase2::<16>::from_dec(value)), format!("{:016b}", value));
+        }
+        for value in [0, 1, 0xDEAD_BEEF, u32::MAX].iter() {
+            let word = BitsBase2::<32>::from_dec(*value);
+            assert_eq!(word.as_dec(), *value);
+            assert_eq!(word.ones(), value.count_ones() as usize);
+        }
+        let nibble: BitsBase2<4> = vec![true, false, true].into_iter().collect();
+        assert_eq!(nibble, BitsBase2::from([true, false, true, false]));
+        assert_eq!(format!("{}", nibble), "0101");
+        let byte: BitsBase2<8> = ByteBase2::from_dec(15);
+        assert_eq!(byte.as_dec(), 15);
+    }
+
+    #[test]
+    fn debug_alternate_test() {
+        assert_eq!(format!("{:?}", ByteBase2::from_dec(15)), "00001111");
+        assert_eq!(format!("{:#?}", ByteBase2::from_dec(15)), "00001111 (15)");
+        assert_eq!(format!("{:#?}", ByteBase2::from_dec(0)), "00000000 (0)");
+        assert_eq!(format!("{:#?}", BitsBase2::<16>::from_dec(0xFFFF)), "1111111111111111 (65535)");
+        assert_eq!(format!("{:#?}", BitsBase2::from([true;128])), format!("{} ({})", "1".repeat(128), u128::MAX));
+        assert_eq!(format!("{:#?}", BitsBase2::from([true;129])), "1".repeat(129));
+        for byte in ByteBase2::all() {
+            let pretty = format!("{:#?}", byte);
+            assert!(pretty.starts_with(&format!("{:?}", byte)));
+            assert!(pretty.ends_with(&format!("({})", byte.as_dec())));
+        }
+    }
+
+    #[test]
+    fn iter_mut_test() {
+        for byte in ByteBase2::all() {
+            let mut inverted = byte;
+            for bit in inverted.iter_mut() {
+                *bit = !*bit;
+            }
+            assert_eq!(inverted, !byte);
+        }
+        let mut byte = ByteBase2::from_dec(0);
+        *byte.iter_mut().next().unwrap() = true;
+        assert_eq!(byte, ByteBase2::from_dec(1));
+        assert_eq!(byte.iter_mut().count(), 8);
+        let mut word = BitsBase2::<16>::from_dec(0);
+        word.iter_mut().skip(8).for_each(|bit| *bit = true);
+        assert_eq!(word.as_dec(), 0xFF00);
+    }
+
+    #[test]
+    fn map_bits_test() {
+        let byte = ByteBase2::from_dec(0xFF).map_bits(|idx, bit| bit && idx % 2 == 0);
+        assert_eq!(byte, ByteBase2::from_dec(0b0101_0101));
+        let byte = ByteBase2::from_dec(0).map_bits(|idx, _| idx >= 4);
+        assert_eq!(byte, ByteBase2::from_dec(0xF0));
+        for byte in ByteBase2::all() {
+            assert_eq!(byte.map_bits(|_, bit| !bit), !byte);
+            assert_eq!(byte.map_bits(|_, bit| bit), byte);
+            let mut visited = Vec::new();
+            byte.for_each_bit(|idx, bit| visited.push((idx, bit)));
+            assert_eq!(visited, byte.iter().enumerate().collect::<Vec<(usize, bool)>>());
+        }
+    }
+
+    #[test]
+    fn fold_bits_test() {
+        for byte in ByteBase2::all() {
+            assert_eq!(byte.fold_bits(0, |ones, bit| ones + bit as usize), byte.ones());
+            assert_eq!(byte.fold_bits(false, |parity, bit| parity ^ bit), byte.ones() % 2 == 1);
+            let (value, _) = byte.fold_bits((0_u8, 1_u8), |(value, weight), bit| {
+                (value + bit as u8 * weight, weight.wrapping_mul(2))
+            });
+            assert_eq!(value, byte.as_dec());
+            let value = byte.iter().rev().fold(0_u8, |value, bit| value << 1 | bit as u8);
+            assert_eq!(value, byte.as_dec());
+        }
+    }
+
+    #[test]
+    fn from_digits_test() {
+        assert_eq!(ByteBase2::from_digits([1, 1, 0, 0, 0, 0, 0, 0]), Ok(ByteBase2::from_dec(3)));
+        assert_eq!(ByteBase2::from_digits([0;8]), Ok(ByteBase2::from_dec(0)));
+        assert_eq!(ByteBase2::from_digits([1;8]), Ok(ByteBase2::from_dec(255)));
+        assert_eq!(ByteBase2::from_digits([0, 0, 0, 0, 0, 0, 0, 2]), Err(InvalidPattern));
+        assert_eq!(ByteBase2::from_digits([1, 0, 255, 0, 0, 0, 0, 0]), Err(InvalidPattern));
+        for byte in ByteBase2::all() {
+            let mut digits = [0;8];
+            for (digit, bit) in digits.iter_mut().zip(byte.iter()) {
+                *digit = bit as u8;
+            }
+            assert_eq!(ByteBase2::from_digits(digits), Ok(byte));
+        }
+    }
+
+    #[test]
+    fn set_nibble_test() {
+        let mut byte = ByteBase2::from_dec(0);
+        assert_eq!(byte.set_high_nibble(0xA), Ok(()));
+        assert_eq!(byte.set_low_nibble(0xB), Ok(()));
+        assert_eq!(byte, ByteBase2::from_dec(0xAB));
+        assert_eq!(byte.set_high_nibble(16), Err(OutOfRange));
+        assert_eq!(byte.set_low_nibble(255), Err(OutOfRange));
+        assert_eq!(byte, ByteBase2::from_dec(0xAB));
+        for value in 0..=255 {
+            for nibble in 0..16 {
+                let mut byte = ByteBase2::from_dec(value);
+                byte.set_high_nibble(nibble).unwrap();
+                assert_eq!(byte.high_nibble(), nibble);
+                assert_eq!(byte.low_nibble(), value & 0x0F);
+                let mut byte = ByteBase2::from_dec(value);
+                byte.set_low_nibble(nibble).unwrap();
+                assert_eq!(byte.high_nibble(), value >> 4);
+                assert_eq!(byte.low_nibble(), nibble);
+            }
+        }
+    }
+
+    #[test]
+    fn combine_nibbles_test() {
+        assert_eq!(ByteBase2::combine_nibbles(0xA, 0xB), Ok(ByteBase2::from_dec(0xAB)));
+        assert_eq!(ByteBase2::combine_nibbles(0, 0), Ok(ByteBase2::from_dec(0)));
+        assert_eq!(ByteBase2::combine_nibbles(15, 15), Ok(ByteBase2::from_dec(255)));
+        assert_eq!(ByteBase2::combine_nibbles(16, 0), Err(OutOfRange));
+        assert_eq!(ByteBase2::combine_nibbles(0, 16), Err(OutOfRange));
+        for byte in ByteBase2::all() {
+            assert_eq!(ByteBase2::combine_nibbles(byte.high_nibble(), byte.low_nibble()), Ok(byte));
+        }
+    }
+
+    #[test]
+    fn bcd_test() {
+        assert_eq!(ByteBase2::to_bcd(42), Ok(ByteBase2::from_dec(0b0100_0010)));
+        assert_eq!(ByteBase2::to_bcd(0), Ok(ByteBase2::from_dec(0)));
+        assert_eq!(ByteBase2::to_bcd(99), Ok(ByteBase2::from_dec(0x99)));
+        assert_eq!(ByteBase2::to_bcd(100), Err(OutOfRange));
+        assert_eq!(ByteBase2::to_bcd(255), Err(OutOfRange));
+        assert_eq!(ByteBase2::from_dec(0x4A).from_bcd(), Err(InvalidBcd));
+        assert_eq!(ByteBase2::from_dec(0xA4).from_bcd(), Err(InvalidBcd));
+        assert_eq!(ByteBase2::from_dec(0xFF).from_bcd(), Err(InvalidBcd));
+        for value in 0..=99 {
+            assert_eq!(ByteBase2::to_bcd(value).unwrap().from_bcd(), Ok(value));
+        }
+        let valid = ByteBase2::all().filter(|byte| byte.from_bcd().is_ok()).count();
+        assert_eq!(valid, 100);
+    }
+
+    #[test]
+    fn bit_index_test() {
+        let names = [Bit::Bit0, Bit::Bit1, Bit::Bit2, Bit::Bit3, Bit::Bit4, Bit::Bit5, Bit::Bit6, Bit::Bit7];
+        for byte in ByteBase2::all() {
+            for (idx, name) in names.iter().enumerate() {
+                assert_eq!(byte[*name], byte[idx]);
+            }
+        }
+        let mut byte = ByteBase2::from_dec(0);
+        byte[Bit::Bit7] = true;
+        assert_eq!(byte, ByteBase2::from_dec(0x80));
+        byte[Bit::Bit0] = true;
+        byte[Bit::Bit7] = false;
+        assert_eq!(byte, ByteBase2::from_dec(0x01));
+    }
+
+    #[test]
+    fn rotate_modulo_test() {
+        for byte in ByteBase2::all() {
+            assert_eq!(byte.rotate_left(8), byte.rotate_left(0));
+            assert_eq!(byte.rotate_left(0), byte);
+            assert_eq!(byte.rotate_right(0), byte);
+            assert_eq!(byte.rotate_right(8), byte);
+            assert_eq!(byte.rotate_left(u32::MAX), byte.rotate_left(7));
+            assert_eq!(byte.rotate_right(u32::MAX), byte.rotate_right(7));
+            assert_eq!(byte.rotate_left(u32::MAX).as_dec(), byte.as_dec().rotate_left(u32::MAX));
+            assert_eq!(byte.rotate_right(u32::MAX - 4).as_dec(), byte.as_dec().rotate_right(u32::MAX - 4));
+            for n in 0..8 {
+                assert_eq!(byte.rotate_left(n + 8 * 1000), byte.rotate_left(n));
+                assert_eq!(byte.rotate_right(n + 8 * 1000), byte.rotate_right(n));
+            }
+            for n in 0..32 {
+                assert_eq!(byte.rotate_left(n).as_dec(), byte.as_dec().rotate_left(n));
+                assert_eq!(byte.rotate_right(n).as_dec(), byte.as_dec().rotate_right(n));
+            }
+        }
+    }
+
+    #[test]
+    fn cmp_signed_test() {
+        use std::cmp::Ordering;
+        let (min, max) = (ByteBase2::from_dec(0x80), ByteBase2::from_dec(0x7F));
+        assert_eq!(min.cmp_signed(&max), Ordering::Less);
+        assert_eq!(min.cmp(&max), Ordering::Greater);
+        assert_eq!(ByteBase2::from_dec(0xFF).cmp_signed(&ByteBase2::from_dec(1)), Ordering::Less);
+        assert_eq!(ByteBase2::from_dec(5).cmp_signed(&ByteBase2::from_dec(5)), Ordering::Equal);
+        for a in ByteBase2::all() {
+            for b in ByteBase2::all().step_by(7) {
+                assert_eq!(a.cmp_signed(&b), (a.as_dec() as i8).cmp(&(b.as_dec() as i8)));
+                if a[7] == b[7] { assert_eq!(a.cmp_signed(&b), a.cmp(&b)); }
+                else if a != b { assert_ne!(a.cmp_signed(&b), a.cmp(&b)); }
+            }
+        }
     }
 }